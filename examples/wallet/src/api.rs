@@ -1,4 +1,4 @@
-use near_client::{core::hash::CryptoHash, prelude::*};
+use near_client::prelude::*;
 use std::rc::Rc;
 use url::Url;
 
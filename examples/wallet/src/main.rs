@@ -26,10 +26,9 @@ enum NetworkType {
 
 #[derive(Serialize, Deserialize)]
 struct User {
-    account_id: AccountId,
-    secret_key: Ed25519SecretKey,
+    #[serde(flatten)]
+    signer: Signer,
     network_type: NetworkType,
-    nonce: Nonce,
 }
 
 fn main() {
@@ -40,13 +39,7 @@ fn main() {
     let (on_signer, signer_setter) = create_signal(None);
     let on_login = on_login(signer_setter);
 
-    let signer = read_user().map(|user| {
-        Rc::new(Signer::from_secret(
-            user.secret_key,
-            user.account_id,
-            user.nonce,
-        ))
-    });
+    let signer = read_user().map(|user| Rc::new(user.signer));
 
     if let Some(signer) = signer.clone() {
         signer_setter.set(Some(signer));
@@ -89,16 +82,12 @@ fn on_login(
         spawn_local(async move {
             match api::signer(network_type.into(), account_id, sk).await {
                 Ok(signer) => {
-                    let sk_copy =
-                        Ed25519SecretKey::try_from_bytes(signer.secret_key().as_bytes()).unwrap();
-                    let account_id = signer.account().clone();
-                    write_user(User {
-                        account_id,
-                        secret_key: sk_copy,
+                    let user = User {
+                        signer,
                         network_type,
-                        nonce: signer.nonce(),
-                    });
-                    signer_setter.set(Some(Rc::new(signer)));
+                    };
+                    write_user(&user);
+                    signer_setter.set(Some(Rc::new(user.signer)));
                 }
                 Err(err) => {
                     log::error!("Can't recover the secret key cause of {err}");
@@ -113,7 +102,7 @@ fn read_user() -> Option<User> {
     LocalStorage::get::<User>("user").ok()
 }
 
-fn write_user(user: User) {
+fn write_user(user: &User) {
     let _ = LocalStorage::set("user", user);
 }
 
@@ -1,6 +1,6 @@
 use crate::{api, components::EditText, read_user};
 use leptos::{html::Input, *};
-use near_client::{core::hash::CryptoHash, prelude::*};
+use near_client::prelude::*;
 use std::{rc::Rc, str::FromStr};
 
 #[component]
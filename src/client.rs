@@ -1,86 +1,255 @@
 use crate::{
     components::{
-        CallResult, TransactionInfo, ViewAccessKey, ViewAccessKeyList, ViewAccessKeyListResult,
-        ViewAccessKeyResult, ViewResult, ViewStateResult,
+        ActionsLimits, CallResult, Credentials, GasPriceView, ProtocolConfigView, StateChangesView,
+        StaticStatus, TransactionInfo, ViewAccessKey, ViewAccessKeyList, ViewAccessKeyListResult,
+        ViewAccessKeyResult, ViewAccountResult, ViewResult, ViewStateResult,
     },
+    gas_to_human,
     near_primitives_light::{
         transaction::{
-            Action, AddKeyAction, CreateAccountAction, DeleteAccountAction, DeleteKeyAction,
-            DeployContractAction, FunctionCallAction, TransferAction,
+            Action, AddKeyAction, CreateAccountAction, DelegateAction, DeleteAccountAction,
+            DeleteKeyAction, DeployContractAction, FunctionCallAction, NonDelegateAction,
+            SignedDelegateAction, SignedTransaction, StakeAction, Transaction, TransferAction,
+        },
+        types::{
+            BlockId, BlockReference, ChunkReference, Finality, StateChanges, StateChangesRequest,
+            TransactionOrReceiptId,
         },
-        types::Finality,
         views::{
-            AccessKeyListView, AccessKeyView, BlockView, ExecutionOutcomeWithIdView,
-            FinalExecutionOutcomeView, FinalExecutionStatus, StatusResponse,
+            AccessKeyInfoView, AccessKeyListView, AccessKeyView, BlockView, ChunkView,
+            ContractCodeView, CostGasUsed, EpochId, EpochValidatorInfo, ExecutionMetadataView,
+            ExecutionOutcomeView, ExecutionOutcomeWithIdView, FinalExecutionOutcomeView,
+            FinalExecutionOutcomeWithReceiptView, FinalExecutionStatus, LightClientProof,
+            QueryError, StatusResponse,
         },
     },
-    prelude::{transaction_errors::TxExecutionErrorContainer, InvalidTxError, TxExecutionError},
+    prelude::{
+        transaction_errors::TxExecutionErrorContainer, InvalidAccessKeyError, InvalidTxError,
+        TxExecutionError,
+    },
     rpc::{client::RpcClient, CauseKind, Error as RpcError, NearError, NearErrorVariant},
-    utils::{extract_logs, serialize_arguments, serialize_transaction},
+    utils::{
+        code_hash, extract_logs, serialize_arguments, serialize_transaction, sign_transaction,
+    },
     Error, Result, ViewAccessKeyCall,
 };
 use near_primitives_core::{
-    account::{id::AccountId, AccessKey, AccessKeyPermission, Account},
+    account::{id::AccountId, AccessKey, AccessKeyPermission, Account, FunctionCallPermission},
     hash::CryptoHash,
-    types::{Balance, Gas, Nonce},
+    types::{Balance, BlockHeight, Gas, Nonce, StorageUsage},
 };
 use std::{
     ops::{Deref, DerefMut},
-    sync::atomic::{AtomicU64, Ordering},
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+    time::Duration,
 };
 
-use crate::crypto::prelude::*;
+use crate::crypto::{prelude::*, SECP256K1};
 use base64::prelude::*;
-use serde::de::DeserializeOwned;
+use borsh::BorshDeserialize;
+use futures::{
+    future::{self, Either},
+    stream, Future, Stream,
+};
+use futures_timer::Delay;
+use rand::Rng;
+use serde::{
+    de::DeserializeOwned, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
 use serde_json::{json, Value};
 use url::Url;
 
 type AtomicNonce = AtomicU64;
 
+/// The longest delay between the transaction retries, the exponential backoff is clamped to it
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(600);
+
+/// The shortest interval between the transaction status polls, so the RPC isn't flooded
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The interval before the second poll of a receipt outcome in [`NearClient::view_receipt`]
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The total time of waiting for a receipt outcome in [`NearClient::view_receipt`]
+const RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Accounts with a larger storage usage can't be deleted by the protocol
+const MAX_ACCOUNT_DELETION_STORAGE_USAGE: StorageUsage = 10_000;
+
 /// Used for signing a transactions
 pub struct Signer {
-    keypair: Keypair,
+    key: SignerKey,
+    public_key: NearPublicKey,
     account_id: AccountId,
     nonce: AtomicNonce,
 }
 
+/// The secret key of a [`Signer`], the access keys could be either ed25519 or secp256k1 keys
+enum SignerKey {
+    Ed25519(Keypair),
+    Secp256k1(Secp256k1SecretKey),
+}
+
+impl SignerKey {
+    fn public_key(&self) -> NearPublicKey {
+        match self {
+            Self::Ed25519(keypair) => keypair.public_key().into(),
+            Self::Secp256k1(secret_key) => secret_key.public_key().into(),
+        }
+    }
+
+    // A string of an ed25519 key is an expanded secret key, the same as near-cli uses
+    fn parse(secret_key: &str) -> crate::crypto::Result<Self> {
+        match secret_key.split_once(':') {
+            Some((SECP256K1, _)) => {
+                Secp256k1SecretKey::from_string(secret_key).map(Self::Secp256k1)
+            }
+            _ => Keypair::from_expanded_secret(secret_key).map(Self::Ed25519),
+        }
+    }
+}
+
+impl std::fmt::Display for SignerKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ed25519(keypair) => f.write_str(&keypair.to_string()),
+            Self::Secp256k1(secret_key) => f.write_str(&secret_key.string()),
+        }
+    }
+}
+
 impl Signer {
-    /// Creates a [`Signer`] from [`str`]
+    /// Creates a [`Signer`] from [`str`], it's either an expanded ed25519 secret key,
+    /// or a secp256k1 secret key, like `secp256k1:...`
     #[allow(clippy::result_large_err)]
     pub fn from_secret_str(secret_key: &str, account_id: AccountId, nonce: Nonce) -> Result<Self> {
-        Ok(Self {
-            keypair: Keypair::from_expanded_secret(secret_key).map_err(Error::CreateSigner)?,
-            account_id,
-            nonce: AtomicU64::new(nonce),
-        })
+        SignerKey::parse(secret_key)
+            .map(|key| Self::new(key, account_id, nonce))
+            .map_err(Error::CreateSigner)
     }
 
     /// Creates a [`Signer`] from [`Ed25519SecretKey`]
     pub fn from_secret(secret_key: Ed25519SecretKey, account_id: AccountId, nonce: Nonce) -> Self {
+        Self::new(
+            SignerKey::Ed25519(Keypair::new(secret_key)),
+            account_id,
+            nonce,
+        )
+    }
+
+    /// Creates a [`Signer`] from [`Secp256k1SecretKey`]
+    pub fn from_secp256k1_secret(
+        secret_key: Secp256k1SecretKey,
+        account_id: AccountId,
+        nonce: Nonce,
+    ) -> Self {
+        Self::new(SignerKey::Secp256k1(secret_key), account_id, nonce)
+    }
+
+    fn new(key: SignerKey, account_id: AccountId, nonce: Nonce) -> Self {
         Self {
-            keypair: Keypair::new(secret_key),
+            public_key: key.public_key(),
+            key,
             account_id,
             nonce: AtomicU64::new(nonce),
         }
     }
 
+    /// Creates a [`Signer`] from [`Ed25519SecretKey`],
+    /// the nonce is fetched from the access key of the derived public key
+    ///
+    /// Arguments
+    ///
+    /// - client - [`NearClient`] that is used to view the access key
+    /// - secret_key - [`Ed25519SecretKey`] of the access key
+    /// - account_id - The account that owns the access key
+    /// - finality - [`Finality`] of the access key view
+    ///
+    /// Errors
+    ///
+    /// - [`Error::ViewAccessKeyCall`], if the access key isn't found
+    pub async fn fetch(
+        client: &NearClient,
+        secret_key: Ed25519SecretKey,
+        account_id: AccountId,
+        finality: Finality,
+    ) -> Result<Self> {
+        let public_key = secret_key.public_key();
+        let access_key = client
+            .view_access_key(&account_id, &public_key, finality)
+            .await?;
+        Ok(Self::from_secret(secret_key, account_id, access_key.nonce))
+    }
+
+    /// Creates a [`Signer`] from the near-cli credentials file,
+    /// that is usually located in the `~/.near-credentials` directory
+    ///
+    /// Arguments
+    ///
+    /// - path - Path to the credentials file
+    /// - nonce - The access key nonce
+    ///
+    /// Errors
+    ///
+    /// - [`Error::ReadCredentials`], if the file can't be read
+    /// - [`Error::CreateSigner`], if the file has a wrong format or the private key is malformed
+    #[allow(clippy::result_large_err)]
+    pub fn from_credentials_file(path: impl AsRef<Path>, nonce: Nonce) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::read(path).map_err(Error::ReadCredentials)?;
+        // The file content isn't included into the error, because it contains a private key
+        let credentials = serde_json::from_slice::<Credentials>(&file).map_err(|err| {
+            Error::CreateSigner(crate::crypto::Error::ConvertFromString {
+                key_name: "credentials",
+                data: path.display().to_string(),
+                cause: err.to_string(),
+            })
+        })?;
+        Self::from_secret_str(&credentials.private_key, credentials.account_id, nonce)
+    }
+
+    /// Returns the [`Signer`] credentials in the near-cli JSON format
+    pub fn to_credentials_json(&self) -> String {
+        json!(Credentials {
+            account_id: self.account_id.clone(),
+            public_key: self.public_key,
+            private_key: self.key.to_string(),
+        })
+        .to_string()
+    }
+
     /// Sign a transaction
     ///
     /// Arguments
     ///
-    /// - data - Serialized transaction with a [Borsh](https://borsh.io/)
-    pub fn sign(&self, data: &[u8]) -> Ed25519Signature {
-        self.keypair.sign(data)
+    /// - hash - A hash of the transaction or the delegate action
+    pub fn sign(&self, hash: &CryptoHash) -> NearSignature {
+        match &self.key {
+            SignerKey::Ed25519(keypair) => keypair.sign(hash.as_ref()).into(),
+            SignerKey::Secp256k1(secret_key) => secret_key
+                .sign(hash.as_ref())
+                // Signing fails only if the prehash is shorter than a half of the curve field
+                .expect("A 32 bytes hash is always signed")
+                .into(),
+        }
     }
 
-    /// Returns the [public key](Ed25519PublicKey) of a [`Signer`]
-    pub fn public_key(&self) -> &Ed25519PublicKey {
-        self.keypair.public_key()
+    /// Returns the [public key](NearPublicKey) of a [`Signer`]
+    pub fn public_key(&self) -> &NearPublicKey {
+        &self.public_key
     }
 
-    /// Returns the [secret key](Ed25519SecretKey) of a [`Signer`]
-    pub fn secret_key(&self) -> &Ed25519SecretKey {
-        self.keypair.secret_key()
+    /// Returns the [secret key](Ed25519SecretKey) of a [`Signer`],
+    /// or [`None`] if the signer uses a secp256k1 key
+    pub fn secret_key(&self) -> Option<&Ed25519SecretKey> {
+        match &self.key {
+            SignerKey::Ed25519(keypair) => Some(keypair.secret_key()),
+            SignerKey::Secp256k1(_) => None,
+        }
     }
 
     /// Returns an [account](AccountId) of a [`Signer`]
@@ -103,12 +272,92 @@ impl Signer {
     pub fn increment_nonce(&self, value: u64) {
         self.nonce.fetch_add(value, Ordering::AcqRel);
     }
+
+    /// Increments the key nonce and returns the incremented value,
+    /// that should be used for the next transaction.
+    /// Function is thread safe, so the concurrent transactions get the different nonces
+    pub fn reserve_nonce(&self) -> Nonce {
+        self.nonce.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Computes a hash of the transaction without signing and broadcasting it.
+    /// The hash equals to the transaction id, that will be returned after the broadcast
+    ///
+    /// Arguments
+    ///
+    /// - receiver_id - The transaction receiver [`AccountId`]
+    /// - actions - A list of actions to be applied
+    /// - block_hash - The hash of the block on top of which the transaction is valid
+    /// - nonce - The transaction nonce, usually it's a [`nonce`](Signer::nonce()) + 1
+    pub fn transaction_hash(
+        &self,
+        receiver_id: &AccountId,
+        actions: Vec<Action>,
+        block_hash: CryptoHash,
+        nonce: Nonce,
+    ) -> CryptoHash {
+        let transaction = Transaction {
+            signer_id: self.account_id.clone(),
+            public_key: self.public_key,
+            nonce,
+            receiver_id: receiver_id.clone(),
+            block_hash,
+            actions,
+        };
+
+        let (hash, ..) = transaction.get_hash_and_size();
+        hash
+    }
+}
+
+/// Serialized as `{"account_id": ..., "secret_key": "ed25519:...", "nonce": ...}`,
+/// the secret key of a secp256k1 signer is prefixed with `secp256k1:`
+impl Serialize for Signer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut signer = serializer.serialize_struct("Signer", 3)?;
+        signer.serialize_field("account_id", &self.account_id)?;
+        match &self.key {
+            SignerKey::Ed25519(keypair) => {
+                signer.serialize_field("secret_key", keypair.secret_key())?
+            }
+            SignerKey::Secp256k1(secret_key) => signer.serialize_field("secret_key", secret_key)?,
+        }
+        signer.serialize_field("nonce", &self.nonce())?;
+        signer.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Signer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct SignerView {
+            account_id: AccountId,
+            secret_key: String,
+            nonce: Nonce,
+        }
+
+        let SignerView {
+            account_id,
+            secret_key,
+            nonce,
+        } = SignerView::deserialize(deserializer)?;
+        let key = match secret_key.split_once(':') {
+            Some((SECP256K1, _)) => {
+                Secp256k1SecretKey::from_string(&secret_key).map(SignerKey::Secp256k1)
+            }
+            _ => Ed25519SecretKey::from_string(&secret_key)
+                .map(|secret_key| SignerKey::Ed25519(Keypair::new(secret_key))),
+        }
+        .map_err(serde::de::Error::custom)?;
+        Ok(Self::new(key, account_id, nonce))
+    }
 }
 
 /// Near RPC client
 #[derive(Clone)]
 pub struct NearClient {
     pub(crate) rpc_client: RpcClient,
+    static_status: Arc<OnceLock<StaticStatus>>,
 }
 
 impl NearClient {
@@ -117,23 +366,96 @@ impl NearClient {
     /// ## Arguments
     ///
     /// - url - A RPC Endpoint [Url](https://docs.near.org/api/rpc/providers)
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::CreateClient`], if the url isn't an http or https endpoint
     #[allow(clippy::result_large_err)]
     pub fn new(url: Url) -> Result<Self> {
         Ok(Self {
             rpc_client: RpcClient::new(url).map_err(Error::CreateClient)?,
+            static_status: Default::default(),
+        })
+    }
+
+    /// Creates a new client, that bounds each request with a `timeout`
+    ///
+    /// ## Arguments
+    ///
+    /// - url - A RPC Endpoint [Url](https://docs.near.org/api/rpc/providers)
+    /// - timeout - Timeout of a single RPC request
+    #[allow(clippy::result_large_err)]
+    pub fn new_with_timeout(url: Url, timeout: Duration) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcClient::new_with_timeout(url, timeout).map_err(Error::CreateClient)?,
+            static_status: Default::default(),
         })
     }
 
+    /// Creates a client, that sends the requests with the existing [`reqwest::Client`],
+    /// so they share its connection pool, proxy and TLS configuration
+    ///
+    /// Arguments
+    ///
+    /// - client - [`reqwest::Client`]
+    /// - url - It's an RPC endpoint [`Url`]
+    pub fn from_reqwest(client: reqwest::Client, url: Url) -> Self {
+        Self {
+            rpc_client: RpcClient::from_parts(client, url),
+            static_status: Default::default(),
+        }
+    }
+
+    /// Creates a client, that sends the requests with the `transport` instead of HTTP
+    ///
+    /// Arguments
+    ///
+    /// - transport - [`Transport`](crate::transport::Transport), e.g. the `MockTransport`
+    /// with canned responses, that is enabled with the `mock` feature
+    pub fn from_transport(transport: impl crate::transport::Transport + 'static) -> Self {
+        Self {
+            rpc_client: RpcClient::from_transport(transport),
+            static_status: Default::default(),
+        }
+    }
+
     /// Queries network and returns block for given height or hash
-    pub async fn block(&self, finality: Finality) -> Result<CryptoHash> {
+    ///
+    /// Arguments
+    ///
+    /// - block_reference - [`Finality`], [`BlockId`] or [`BlockReference`]
+    pub async fn block(&self, block_reference: impl Into<BlockReference>) -> Result<CryptoHash> {
+        self.block_view(block_reference.into())
+            .await
+            .map(|block_view| block_view.header.hash)
+    }
+
+    /// Queries the id of the current epoch
+    ///
+    /// Arguments
+    ///
+    /// - finality - [`Finality`]
+    pub async fn current_epoch_id(&self, finality: Finality) -> Result<EpochId> {
+        self.block_view(finality.into())
+            .await
+            .map(|block_view| block_view.header.epoch_id)
+    }
+
+    /// Queries the whole block with its header and chunks,
+    /// see [`block`](NearClient::block()) if only the block hash is needed
+    ///
+    /// Arguments
+    ///
+    /// - block_reference - [`BlockReference`], the block finality or the block id
+    pub async fn block_view(&self, block_reference: BlockReference) -> Result<BlockView> {
+        let params = serde_json::to_value(block_reference).map_err(Error::ArgsSerialization)?;
         self.rpc_client
-            .request("block", Some(json!({ "finality": finality })))
+            .request("block", Some(params))
             .await
             .map_err(Error::BlockCall)
             .and_then(|block_res| {
                 serde_json::from_value::<BlockView>(block_res).map_err(Error::DeserializeBlock)
             })
-            .map(|block_view| block_view.header.hash)
     }
 
     /// Allows you to call a contract method as a view function.
@@ -148,20 +470,109 @@ impl NearClient {
         &'a self,
         contract_id: &'a AccountId,
         finality: Finality,
-        method: &'static str,
+        method: &'a str,
+        args: Option<Value>,
+    ) -> Result<ViewOutput<T>> {
+        self.view_at(contract_id, finality.into(), method, args)
+            .await
+    }
+
+    /// The same as [`view`](NearClient::view()), but allows to call a contract method
+    /// at the specific block
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - block_reference - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    /// - method - Function that is declared in a smart contract
+    /// - args - Function arguments, could be empty
+    pub async fn view_at<'a, T: DeserializeOwned>(
+        &'a self,
+        contract_id: &'a AccountId,
+        block_reference: BlockReference,
+        method: &'a str,
+        args: Option<Value>,
+    ) -> Result<ViewOutput<T>> {
+        let output = self
+            .view_call(contract_id, block_reference, method, args)
+            .await?;
+
+        Ok(ViewOutput {
+            data: serde_json::from_slice(&output.data).map_err(Error::DeserializeResponseView)?,
+            logs: output.logs,
+        })
+    }
+
+    /// The same as [`view`](NearClient::view()), but returns the result bytes as is,
+    /// without the JSON deserialization. Allows to handle a non-JSON output of a contract method
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - finality - [`Finality`]
+    /// - method - Function that is declared in a smart contract
+    /// - args - Function arguments, could be empty
+    pub async fn view_raw<'a>(
+        &'a self,
+        contract_id: &'a AccountId,
+        finality: Finality,
+        method: &'a str,
+        args: Option<Value>,
+    ) -> Result<ViewOutput<Vec<u8>>> {
+        self.view_call(contract_id, finality.into(), method, args)
+            .await
+    }
+
+    /// The same as [`view`](NearClient::view()), but the result is deserialized with
+    /// [borsh](https://docs.rs/borsh), for the contract methods that return a borsh-encoded data
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - finality - [`Finality`]
+    /// - method - Function that is declared in a smart contract
+    /// - args - Function arguments, could be empty
+    pub async fn view_borsh<'a, T: BorshDeserialize>(
+        &'a self,
+        contract_id: &'a AccountId,
+        finality: Finality,
+        method: &'a str,
         args: Option<Value>,
     ) -> Result<ViewOutput<T>> {
+        let output = self
+            .view_call(contract_id, finality.into(), method, args)
+            .await?;
+
+        Ok(ViewOutput {
+            data: T::try_from_slice(&output.data).map_err(Error::DeserializeViewCallBorsh)?,
+            logs: output.logs,
+        })
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(contract_id = %contract_id, method))
+    )]
+    async fn view_call(
+        &self,
+        contract_id: &AccountId,
+        block_reference: BlockReference,
+        method: &str,
+        args: Option<Value>,
+    ) -> Result<ViewOutput<Vec<u8>>> {
         let args = BASE64_STANDARD_NO_PAD.encode(serialize_arguments(args)?);
         self.rpc_client
             .request(
                 "query",
-                Some(json!({
-                    "request_type": "call_function",
-                    "finality": finality,
-                    "account_id": contract_id,
-                    "method_name": method,
-                    "args_base64": args
-                })),
+                Some(query_params(
+                    json!({
+                        "request_type": "call_function",
+                        "account_id": contract_id,
+                        "method_name": method,
+                        "args_base64": args
+                    }),
+                    block_reference,
+                )?),
             )
             .await
             .map_err(Error::ViewCall)
@@ -171,26 +582,134 @@ impl NearClient {
             .and_then(|view_res| match view_res.result {
                 CallResult::Ok(data) => Ok(ViewOutput {
                     logs: view_res.logs,
-                    data: serde_json::from_slice(&data).map_err(Error::DeserializeResponseView)?,
+                    data,
                 }),
-                CallResult::Err(cause) => Err(Error::ViewCall(RpcError::NearProtocol(
-                    NearError::handler(cause),
-                ))),
+                CallResult::Err(cause) => {
+                    let QueryError { error, logs } = query_error(cause, view_res.logs);
+                    Err(Error::ViewFunctionCall { error, logs })
+                }
             })
     }
 
+    /// Calls a contract view method, that returns a business logic [`Result`](std::result::Result)
+    /// serialized as `{"Ok": ...}` or `{"Err": ...}`, like [near-sdk](https://docs.rs/near-sdk) does.
+    /// The outer [`Result`] contains a protocol level error, the inner one an error of the contract
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - finality - [`Finality`]
+    /// - method - Function that is declared in a smart contract
+    /// - args - Function arguments, could be empty
+    pub async fn view_result<'a, T: DeserializeOwned, E: DeserializeOwned>(
+        &'a self,
+        contract_id: &'a AccountId,
+        finality: Finality,
+        method: &'a str,
+        args: Option<Value>,
+    ) -> Result<ViewOutput<std::result::Result<T, E>>> {
+        self.view(contract_id, finality, method, args).await
+    }
+
+    /// Calls a paginated contract view method and yields the items page by page.
+    /// The method should accept `from_index` and `limit` arguments and return an array of items,
+    /// `from_index` is passed as a string, like the `U128` index of NEP-171 contracts.
+    /// The stream stops after the first page that contains less than `page_size` items.
+    /// Use [`view_paginated_with`](NearClient::view_paginated_with()) for other argument names or encoding
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - method - Function that is declared in a smart contract
+    /// - page_size - Amount of items requested per call, passed as a `limit` argument
+    /// - finality - [`Finality`]
+    pub fn view_paginated<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        contract_id: &'a AccountId,
+        method: &'a str,
+        page_size: u64,
+        finality: Finality,
+    ) -> impl Stream<Item = Result<T>> + 'a {
+        self.view_paginated_with(contract_id, method, Pagination::new(page_size), finality)
+    }
+
+    /// The same as [`view_paginated`](NearClient::view_paginated()),
+    /// but the pagination arguments are described by [`Pagination`]
+    ///
+    /// Arguments
+    ///
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - method - Function that is declared in a smart contract
+    /// - pagination - [`Pagination`] arguments of the method
+    /// - finality - [`Finality`]
+    pub fn view_paginated_with<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        contract_id: &'a AccountId,
+        method: &'a str,
+        pagination: Pagination,
+        finality: Finality,
+    ) -> impl Stream<Item = Result<T>> + 'a {
+        let state = PageState {
+            from_index: 0,
+            page: Vec::new().into_iter(),
+            finished: false,
+        };
+
+        stream::unfold(state, move |mut state| {
+            let finality = finality.clone();
+            let pagination = pagination.clone();
+            async move {
+                let page_size = pagination.page_size;
+                loop {
+                    if let Some(item) = state.page.next() {
+                        return Some((Ok(item), state));
+                    }
+
+                    if state.finished {
+                        return None;
+                    }
+
+                    let args = pagination.args(state.from_index);
+                    match self
+                        .view::<Vec<T>>(contract_id, finality.clone(), method, Some(args))
+                        .await
+                    {
+                        Ok(page) => {
+                            let page = page.data();
+                            let len = page.len() as u64;
+                            state.finished = len == 0 || len < page_size;
+                            state.from_index += len;
+                            state.page = page.into_iter();
+                        }
+                        Err(err) => {
+                            state.finished = true;
+                            return Some((Err(err), state));
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     /// Returns information about a single access key for given account
     ///
     /// Arguments
     ///
     /// - account_id - The user [`AccountId`] in a Near network
-    /// - public_key - The user [`Ed25519PublicKey`] in a Near network
+    /// - public_key - The user [`NearPublicKey`] in a Near network
+    /// - finality - [`Finality`]
+    ///
+    /// Seeding a [`Signer`] with a nonce that was received with [`Finality::None`] is risky
+    /// if transactions are sent concurrently, the optimistic nonce could be already superseded
+    /// by a pending transaction, which leads to the **InvalidNonce** error.
+    /// Use [`view_access_key_final`](NearClient::view_access_key_final()) in this case.
     pub async fn view_access_key(
         &self,
         account_id: &AccountId,
-        public_key: &Ed25519PublicKey,
+        public_key: impl Into<NearPublicKey>,
         finality: Finality,
     ) -> Result<AccessKeyView> {
+        let public_key = public_key.into();
         self.rpc_client
             .request(
                 "query",
@@ -203,90 +722,483 @@ impl NearClient {
             )
             .await
             .map_err(|err| Error::ViewAccessKeyCall(ViewAccessKeyCall::Rpc(err)))
-            .and_then(|it| {
-                serde_json::from_value::<ViewAccessKey>(it)
-                    .map_err(Error::DeserializeAccessKeyViewCall)
-            })
-            .and_then(|view_access_key| match view_access_key.result {
-                ViewAccessKeyResult::Ok(access_key_view) => Ok(access_key_view),
-                ViewAccessKeyResult::Err { error, logs } => {
-                    Err(Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError {
-                        error,
-                        logs,
-                    }))
-                }
-            })
+            .and_then(access_key_view)
     }
 
-    /// Returns list of all access keys for the given account
+    /// Returns multiple access keys of the account within a single batch request,
+    /// for example to get the nonces of the multisig keys
     ///
     /// Arguments
+    ///
     /// - account_id - The user [`AccountId`] in a Near network
-    pub async fn view_access_key_list(
+    /// - public_keys - The [`NearPublicKey`]s of the access keys
+    /// - finality - [`Finality`]
+    ///
+    /// Return
+    ///
+    /// The [`AccessKeyView`] results are in the same order as the public keys,
+    /// a key that isn't found has its own error.
+    /// If the batch request fails, an error is returned for all of the keys
+    pub async fn view_access_keys(
         &self,
         account_id: &AccountId,
+        public_keys: impl IntoIterator<Item = impl Into<NearPublicKey>>,
         finality: Finality,
-    ) -> Result<AccessKeyListView> {
-        self.rpc_client
-            .request(
-                "query",
-                Some(json!({
-                    "request_type": "view_access_key_list",
-                    "finality": finality,
-                    "account_id": account_id
-                })),
-            )
-            .await
-            .map_err(|err| Error::ViewAccessKeyListCall(ViewAccessKeyCall::Rpc(err)))
-            .and_then(|it| {
-                serde_json::from_value::<ViewAccessKeyList>(it)
-                    .map_err(Error::DeserializeAccessKeyListViewCall)
+    ) -> Result<Vec<Result<AccessKeyView>>> {
+        let calls = public_keys
+            .into_iter()
+            .map(|public_key| {
+                (
+                    "query",
+                    Some(json!({
+                        "request_type": "view_access_key",
+                        "finality": finality,
+                        "account_id": account_id,
+                        "public_key": public_key.into(),
+                    })),
+                )
             })
-            .and_then(|view_access_key_list| match view_access_key_list.result {
-                ViewAccessKeyListResult::Ok(access_key_list_view) => Ok(access_key_list_view),
-                ViewAccessKeyListResult::Err { error, logs } => Err(Error::ViewAccessKeyListCall(
-                    ViewAccessKeyCall::ParseError { error, logs },
-                )),
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .rpc_client
+            .request_batch(calls)
+            .await
+            .map_err(|err| Error::ViewAccessKeyCall(ViewAccessKeyCall::Rpc(err)))?
+            .into_iter()
+            .map(|resp| {
+                resp.map_err(|err| Error::ViewAccessKeyCall(ViewAccessKeyCall::Rpc(err)))
+                    .and_then(access_key_view)
             })
+            .collect())
     }
 
-    /// Returns information regarding contract state
-    /// in a key-value sequence representation
+    /// The same as [`view_access_key`](NearClient::view_access_key()),
+    /// but the access key is always requested with the [`Finality::Final`]
     ///
     /// Arguments
     ///
-    /// - account_id - The contract [`AccountId`] in a Near network
-    pub async fn view_contract_state(&self, account_id: &AccountId) -> Result<ViewStateResult> {
-        self.rpc_client
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - public_key - The user [`NearPublicKey`] in a Near network
+    pub async fn view_access_key_final(
+        &self,
+        account_id: &AccountId,
+        public_key: impl Into<NearPublicKey>,
+    ) -> Result<AccessKeyView> {
+        self.view_access_key(account_id, public_key, Finality::Final)
+            .await
+    }
+
+    /// Checks that the [`Signer`] key is registered as an access key of the signer account
+    ///
+    /// Arguments
+    ///
+    /// - signer - [`Signer`] that contain information regarding user [`Keypair`]
+    /// - finality - [`Finality`]
+    ///
+    /// Return
+    ///
+    /// - ```Ok(true)```, if the access key exists
+    /// - ```Ok(false)```, if the access key isn't found for the account
+    /// - ```Err```, if the RPC call failed
+    pub async fn verify_signer(&self, signer: &Signer, finality: Finality) -> Result<bool> {
+        match self
+            .view_access_key(signer.account(), signer.public_key(), finality)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError { .. })) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the remaining allowance of a function call key,
+    /// the allowance is decreased by the cost of each transaction signed with the key
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - public_key - The user [`NearPublicKey`] in a Near network
+    /// - finality - [`Finality`]
+    ///
+    /// Return
+    ///
+    /// - ```Ok(Some(allowance))```, if the key is a function call key with a limited allowance
+    /// - ```Ok(None)```, if the key is a full access key or its allowance is unlimited
+    pub async fn access_key_allowance(
+        &self,
+        account_id: &AccountId,
+        public_key: impl Into<NearPublicKey>,
+        finality: Finality,
+    ) -> Result<Option<Balance>> {
+        self.view_access_key(account_id, public_key, finality)
+            .await
+            .map(|access_key| access_key.allowance())
+    }
+
+    /// Checks that the remaining allowance of the [`Signer`] key covers a function call,
+    /// before the transaction is sent. The cost is estimated as the attached gas
+    /// multiplied by the gas price, so it's a lower bound of the actual transaction cost
+    ///
+    /// Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - gas - Amount of gas that will be hold for function execution
+    /// - gas_price - The gas price, see [`gas_price`](NearClient::gas_price())
+    /// - finality - [`Finality`]
+    ///
+    /// ## Errors
+    ///
+    /// - [`Error::InvalidAccessKey`] - with [`InvalidAccessKeyError::NotEnoughAllowance`],
+    ///   if the allowance doesn't cover the estimated cost
+    pub async fn check_allowance(
+        &self,
+        signer: &Signer,
+        gas: Gas,
+        gas_price: Balance,
+        finality: Finality,
+    ) -> Result<()> {
+        let public_key = NearPublicKey::from(signer.public_key());
+        let Some(allowance) = self
+            .access_key_allowance(signer.account(), public_key, finality)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let cost = Balance::from(gas).saturating_mul(gas_price);
+        if cost > allowance {
+            return Err(Error::InvalidAccessKey(
+                InvalidAccessKeyError::NotEnoughAllowance {
+                    account_id: signer.account().clone(),
+                    public_key,
+                    allowance,
+                    cost,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns list of all access keys for the given account
+    ///
+    /// Arguments
+    /// - account_id - The user [`AccountId`] in a Near network
+    pub async fn view_access_key_list(
+        &self,
+        account_id: &AccountId,
+        finality: Finality,
+    ) -> Result<AccessKeyListView> {
+        self.rpc_client
             .request(
                 "query",
                 Some(json!({
-                    "request_type": "view_state",
-                    "finality": Finality::Final,
-                    "account_id": account_id,
-                    "prefix_base64": ""
+                    "request_type": "view_access_key_list",
+                    "finality": finality,
+                    "account_id": account_id
                 })),
             )
             .await
+            .map_err(|err| Error::ViewAccessKeyListCall(ViewAccessKeyCall::Rpc(err)))
+            .and_then(|it| {
+                serde_json::from_value::<ViewAccessKeyList>(it)
+                    .map_err(Error::DeserializeAccessKeyListViewCall)
+            })
+            .and_then(|view_access_key_list| match view_access_key_list.result {
+                ViewAccessKeyListResult::Ok(access_key_list_view) => Ok(access_key_list_view),
+                ViewAccessKeyListResult::Err { error, logs } => Err(Error::ViewAccessKeyListCall(
+                    ViewAccessKeyCall::ParseError { error, logs },
+                )),
+            })
+    }
+
+    /// Returns the first full access key of the account with its nonce
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - finality - [`Finality`]
+    ///
+    /// Return
+    ///
+    /// [`Error::NoFullAccessKey`], if the account doesn't have any full access key
+    pub async fn primary_access_key(
+        &self,
+        account_id: &AccountId,
+        finality: Finality,
+    ) -> Result<AccessKeyInfoView> {
+        self.view_access_key_list(account_id, finality)
+            .await?
+            .keys
+            .into_iter()
+            .find(|key| key.access_key.permission == AccessKeyPermission::FullAccess)
+            .map(|key| AccessKeyInfoView {
+                public_key: key.public_key,
+                access_key: key.access_key.into(),
+            })
+            .ok_or_else(|| Error::NoFullAccessKey(account_id.clone()))
+    }
+
+    /// Returns information regarding contract state
+    /// in a key-value sequence representation
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The contract [`AccountId`] in a Near network
+    pub async fn view_contract_state(&self, account_id: &AccountId) -> Result<ViewStateResult> {
+        self.view_contract_state_at(account_id, Finality::Final.into())
+            .await
+    }
+
+    /// Returns information regarding contract state at the specific block
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The contract [`AccountId`] in a Near network
+    /// - block_reference - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    pub async fn view_contract_state_at(
+        &self,
+        account_id: &AccountId,
+        block_reference: BlockReference,
+    ) -> Result<ViewStateResult> {
+        self.view_contract_state_with_prefix(account_id, &[], block_reference)
+            .await
+    }
+
+    /// Returns the contract state records, which keys start with the prefix.
+    /// Allows to read a single collection of the contract without downloading the whole state
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The contract [`AccountId`] in a Near network
+    /// - prefix - Prefix of the storage keys, an empty prefix matches all the records
+    /// - block_reference - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    pub async fn view_contract_state_with_prefix(
+        &self,
+        account_id: &AccountId,
+        prefix: &[u8],
+        block_reference: BlockReference,
+    ) -> Result<ViewStateResult> {
+        self.rpc_client
+            .request(
+                "query",
+                Some(query_params(
+                    json!({
+                        "request_type": "view_state",
+                        "account_id": account_id,
+                        "prefix_base64": BASE64_STANDARD_NO_PAD.encode(prefix)
+                    }),
+                    block_reference,
+                )?),
+            )
+            .await
             .map_err(Error::ViewCall)
             .and_then(|it| {
                 serde_json::from_value::<ViewStateResult>(it).map_err(Error::DeserializeViewCall)
             })
     }
 
+    /// Returns the contract code (Wasm binary) deployed to the account
+    ///
+    /// Arguments
+    ///
+    /// - account_id - The contract [`AccountId`] in a Near network
+    /// - finality - [`Finality`]
+    pub async fn view_contract_code(
+        &self,
+        account_id: &AccountId,
+        finality: Finality,
+    ) -> Result<ContractCodeView> {
+        self.rpc_client
+            .request(
+                "query",
+                Some(json!({
+                    "request_type": "view_code",
+                    "finality": finality,
+                    "account_id": account_id,
+                })),
+            )
+            .await
+            .map_err(Error::ViewCall)
+            .and_then(|it| {
+                serde_json::from_value::<ContractCodeView>(it).map_err(Error::DeserializeViewCall)
+            })
+    }
+
     /// Returns general status of a given node
     /// (sync status, nearcore node version, protocol version, etc),
     /// and the current set of validators.
     pub async fn network_status(&self) -> Result<StatusResponse> {
-        self.rpc_client
+        let status = self
+            .rpc_client
             .request("status", None)
             .await
             .map_err(Error::RpcError)
             .and_then(|it| {
                 serde_json::from_value::<StatusResponse>(it).map_err(Error::DeserializeResponseView)
+            })?;
+
+        self.static_status
+            .get_or_init(|| StaticStatus::from(&status));
+        Ok(status)
+    }
+
+    /// Creates a client, that applies the `timeout` to each of its requests.
+    /// The client shares connections with the current one, so it's cheap to create
+    ///
+    /// ## Arguments
+    ///
+    /// - timeout - Timeout of a single RPC request
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            rpc_client: self.rpc_client.with_timeout(timeout),
+            static_status: Arc::clone(&self.static_status),
+        }
+    }
+
+    /// Creates a client, that sends the `version` in the `jsonrpc` field of its requests,
+    /// for the NEAR-compatible endpoints, that expect a version other than `2.0`
+    ///
+    /// ## Arguments
+    ///
+    /// - version - JSON-RPC version
+    pub fn with_jsonrpc_version(&self, version: &str) -> Self {
+        Self {
+            rpc_client: self.rpc_client.with_jsonrpc_version(version),
+            static_status: Arc::clone(&self.static_status),
+        }
+    }
+
+    /// Returns the chain id of the network.
+    /// The value is cached after the first [`network_status`](NearClient::network_status()) call
+    pub async fn chain_id(&self) -> Result<String> {
+        Ok(self.static_status().await?.chain_id.clone())
+    }
+
+    /// Returns the protocol version of the network.
+    /// The value is cached after the first [`network_status`](NearClient::network_status()) call,
+    /// so it doesn't reflect a protocol upgrade, that happened after
+    pub async fn protocol_version(&self) -> Result<u32> {
+        Ok(self.static_status().await?.protocol_version)
+    }
+
+    /// Returns the height of the latest block
+    ///
+    /// Arguments
+    ///
+    /// - finality - [`Finality`]
+    pub async fn latest_block_height(&self, finality: Finality) -> Result<BlockHeight> {
+        self.block_view(finality.into())
+            .await
+            .map(|block_view| block_view.header.height)
+    }
+
+    async fn static_status(&self) -> Result<&StaticStatus> {
+        if let Some(status) = self.static_status.get() {
+            return Ok(status);
+        }
+
+        let status = self.network_status().await?;
+        Ok(self
+            .static_status
+            .get_or_init(|| StaticStatus::from(&status)))
+    }
+
+    /// Queries the chunk
+    ///
+    /// Arguments
+    ///
+    /// - chunk - [`ChunkReference`], the chunk hash or the block id with the shard id
+    pub async fn chunk(&self, chunk: ChunkReference) -> Result<ChunkView> {
+        self.rpc_client
+            .request("chunk", Some(json!(chunk)))
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|it| {
+                serde_json::from_value::<ChunkView>(it).map_err(Error::DeserializeResponseView)
             })
     }
 
+    /// Queries the gas price
+    ///
+    /// Arguments
+    ///
+    /// - block_id - [`BlockId`], if it's empty the latest block is used
+    pub async fn gas_price(&self, block_id: Option<BlockId>) -> Result<Balance> {
+        self.rpc_client
+            .request("gas_price", Some(json!([block_id])))
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|it| {
+                serde_json::from_value::<GasPriceView>(it).map_err(Error::DeserializeResponseView)
+            })
+            .map(|view| view.gas_price)
+    }
+
+    /// Queries validators of the epoch
+    ///
+    /// Arguments
+    ///
+    /// - block_id - [`BlockId`] of the epoch last block, if it's empty the latest block is used
+    pub async fn validators(&self, block_id: Option<BlockId>) -> Result<EpochValidatorInfo> {
+        self.rpc_client
+            .request("validators", Some(json!([block_id])))
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|it| {
+                serde_json::from_value::<EpochValidatorInfo>(it)
+                    .map_err(Error::DeserializeResponseView)
+            })
+    }
+
+    /// Queries the state changes of accounts, access keys or contract code in the block
+    ///
+    /// Arguments
+    ///
+    /// - request - [`StateChangesRequest`], kind of changes and the accounts to query
+    /// - block_reference - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    pub async fn changes(
+        &self,
+        request: StateChangesRequest,
+        block_reference: BlockReference,
+    ) -> Result<StateChanges> {
+        let request = serde_json::to_value(request).map_err(Error::ArgsSerialization)?;
+
+        self.rpc_client
+            .request(
+                "EXPERIMENTAL_changes",
+                Some(query_params(request, block_reference)?),
+            )
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|it| {
+                serde_json::from_value::<StateChangesView>(it)
+                    .map_err(Error::DeserializeResponseView)
+            })
+            .map(|view| view.changes)
+    }
+
+    /// Queries the protocol config and returns limits, that are applied to the transaction actions
+    ///
+    /// Arguments
+    ///
+    /// - finality - [`Finality`]
+    pub async fn actions_limits(&self, finality: Finality) -> Result<ActionsLimits> {
+        self.rpc_client
+            .request(
+                "EXPERIMENTAL_protocol_config",
+                Some(json!({ "finality": finality })),
+            )
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|it| {
+                serde_json::from_value::<ProtocolConfigView>(it)
+                    .map_err(Error::DeserializeResponseView)
+            })
+            .map(|config| config.runtime_config.wasm_config.limit_config)
+    }
+
     /// Queries status of a transaction by hash,
     /// returning the final transaction result and details of all receipts.
     ///
@@ -306,6 +1218,79 @@ impl NearClient {
         transaction_id: &'a CryptoHash,
         signer: &'a Signer,
     ) -> Result<Output> {
+        let execution_outcome = self
+            .tx_status::<FinalExecutionOutcomeView>(transaction_id, signer)
+            .await?;
+        proceed_outcome(signer, execution_outcome)
+    }
+
+    /// Waits until a transaction is finished, polling its status.
+    /// The interval between polls is doubled after each one
+    ///
+    /// Arguments
+    ///
+    /// - transaction_id - Transaction [`CryptoHash`]
+    /// - signer - [`Signer`] that contain information regarding user [`Keypair`]
+    /// - poll - Interval before the second poll, it's at least 50 milliseconds
+    /// - timeout - Total time of waiting
+    ///
+    /// Errors
+    ///
+    /// [`Error::AwaitTransactionTimeout`], if a transaction isn't finished in time.
+    /// The polling is stopped on the first error, unless a node doesn't know the transaction yet
+    pub async fn await_transaction(
+        &self,
+        transaction_id: &CryptoHash,
+        signer: &Signer,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<Output> {
+        poll_until(poll, timeout, || async {
+            match self
+                .tx_status::<FinalExecutionOutcomeView>(transaction_id, signer)
+                .await
+            {
+                // The transaction isn't known to the node yet
+                Err(Error::ViewTransaction(err)) if err.is_unknown_transaction() => None,
+                Ok(FinalExecutionOutcomeView {
+                    status: FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started,
+                    ..
+                }) => None,
+                Ok(execution_outcome) => Some(proceed_outcome(signer, execution_outcome)),
+                Err(err) => Some(Err(err)),
+            }
+        })
+        .await
+        .unwrap_or(Err(Error::AwaitTransactionTimeout(*transaction_id)))
+    }
+
+    /// Queries status of a transaction by hash, the same way as [`view_transaction`](NearClient::view_transaction()),
+    /// but the outcome also includes all of the receipts, that are generated by the transaction.
+    /// It's useful for debugging of cross-contract calls
+    ///
+    /// Arguments
+    ///
+    /// - transaction_id - Transaction [`CryptoHash`]
+    /// - signer - [`Signer`] that contain information regarding user [`Keypair`]
+    ///
+    /// Return
+    ///
+    /// If a transaction still processing will be returned an error [`Error::ViewTransaction`]
+    pub async fn tx_status_with_receipts(
+        &self,
+        transaction_id: &CryptoHash,
+        signer: &Signer,
+    ) -> Result<FinalExecutionOutcomeWithReceiptView> {
+        self.tx_status(transaction_id, signer).await
+    }
+
+    // The experimental status call returns the receipts as well,
+    // so the outcome could be deserialized either with or without them
+    async fn tx_status<T: DeserializeOwned>(
+        &self,
+        transaction_id: &CryptoHash,
+        signer: &Signer,
+    ) -> Result<T> {
         let params = Value::Array(vec![
             serde_json::to_value(transaction_id)
                 .map_err(|err| Error::SerializeTxViewArg("transaction_id", err))?,
@@ -313,17 +1298,152 @@ impl NearClient {
                 .map_err(|err| Error::SerializeTxViewArg("signer_acc_id", err))?,
         ]);
 
-        let execution_outcome = self
-            .rpc_client
+        self.rpc_client
             .request("EXPERIMENTAL_tx_status", Some(params))
             .await
             .map_err(Error::ViewTransaction)
             .and_then(|execution_outcome| {
-                serde_json::from_value::<FinalExecutionOutcomeView>(execution_outcome)
+                serde_json::from_value::<T>(execution_outcome)
                     .map_err(Error::DeserializeExecutionOutcome)
-            })?;
+            })
+    }
 
-        proceed_outcome(signer, execution_outcome)
+    /// Queries an execution outcome of a transaction or a receipt.
+    /// A transaction outcome is requested with a transaction status call,
+    /// a receipt outcome with a light client proof call, as there is no receipt status call
+    ///
+    /// Arguments
+    ///
+    /// - id - [`TransactionOrReceiptId`]
+    /// - finality - [`Finality`] of the outcome. A transaction status call waits until the transaction
+    ///   is executed in a block of this finality, a receipt outcome is proved with a light client head
+    ///   of this finality
+    pub async fn status_of(
+        &self,
+        id: TransactionOrReceiptId,
+        finality: Finality,
+    ) -> Result<ExecutionOutcomeWithIdView> {
+        match id {
+            TransactionOrReceiptId::Transaction {
+                transaction_hash,
+                sender_id,
+            } => self
+                .rpc_client
+                .request(
+                    "tx",
+                    Some(json!({
+                        "tx_hash": transaction_hash,
+                        "sender_account_id": sender_id,
+                        "wait_until": tx_execution_status(&finality),
+                    })),
+                )
+                .await
+                .map_err(Error::ViewTransaction)
+                .and_then(|execution_outcome| {
+                    serde_json::from_value::<FinalExecutionOutcomeView>(execution_outcome)
+                        .map_err(Error::DeserializeExecutionOutcome)
+                })
+                .map(|execution_outcome| execution_outcome.transaction_outcome),
+            TransactionOrReceiptId::Receipt { .. } => {
+                let light_client_head = self.block(finality).await?;
+                self.light_client_proof(id, light_client_head)
+                    .await
+                    .map(|proof| proof.outcome_proof)
+            }
+        }
+    }
+
+    /// Waits until a receipt is executed and returns its outcome, for example a cross-contract call,
+    /// that is spawned by a transaction. Receipt ids could be taken from
+    /// [`tx_status_with_receipts`](NearClient::tx_status_with_receipts()).
+    /// The outcome is polled for up to 30 seconds, use [`await_receipt`](NearClient::await_receipt())
+    /// to set another interval and timeout
+    ///
+    /// Arguments
+    ///
+    /// - receipt_id - Receipt [`CryptoHash`]
+    /// - receiver_id - The [`AccountId`], where the receipt is executed
+    ///
+    /// Errors
+    ///
+    /// [`Error::AwaitReceiptTimeout`], if a receipt outcome isn't final in time
+    pub async fn view_receipt(
+        &self,
+        receipt_id: CryptoHash,
+        receiver_id: AccountId,
+    ) -> Result<ExecutionOutcomeView> {
+        self.await_receipt(
+            receipt_id,
+            receiver_id,
+            RECEIPT_POLL_INTERVAL,
+            RECEIPT_TIMEOUT,
+        )
+        .await
+    }
+
+    /// Waits until a receipt is executed and returns its outcome, the same way as
+    /// [`view_receipt`](NearClient::view_receipt()), but with the given polling interval and timeout.
+    /// The outcome is available, when a block after the receipt execution is final,
+    /// so it's polled the same way as [`await_transaction`](NearClient::await_transaction())
+    ///
+    /// Arguments
+    ///
+    /// - receipt_id - Receipt [`CryptoHash`]
+    /// - receiver_id - The [`AccountId`], where the receipt is executed
+    /// - poll - Interval before the second poll, it's at least 50 milliseconds
+    /// - timeout - Total time of waiting
+    ///
+    /// Errors
+    ///
+    /// [`Error::AwaitReceiptTimeout`], if a receipt outcome isn't final in time.
+    /// The polling is stopped on the first error, unless a node doesn't know the receipt yet
+    pub async fn await_receipt(
+        &self,
+        receipt_id: CryptoHash,
+        receiver_id: AccountId,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<ExecutionOutcomeView> {
+        poll_until(poll, timeout, || async {
+            let id = TransactionOrReceiptId::Receipt {
+                receipt_id,
+                receiver_id: receiver_id.clone(),
+            };
+
+            match self.status_of(id, Finality::Final).await {
+                // The receipt isn't executed yet, or the block with its outcome isn't final
+                Err(Error::RpcError(err)) if err.is_unknown_receipt() => None,
+                outcome => Some(outcome.map(|execution_outcome| execution_outcome.outcome)),
+            }
+        })
+        .await
+        .unwrap_or(Err(Error::AwaitReceiptTimeout(receipt_id)))
+    }
+
+    /// Queries a proof, that the execution outcome of a transaction or a receipt
+    /// is included into the chain. The proof could be verified with the [`light_client`](crate::light_client)
+    ///
+    /// Arguments
+    ///
+    /// - id - [`TransactionOrReceiptId`]
+    /// - light_client_head - Hash of the last block, that is known to the light client
+    pub async fn light_client_proof(
+        &self,
+        id: TransactionOrReceiptId,
+        light_client_head: CryptoHash,
+    ) -> Result<LightClientProof> {
+        let mut params =
+            serde_json::to_value(&id).map_err(|err| Error::SerializeTxViewArg("id", err))?;
+        params["light_client_head"] = json!(light_client_head);
+
+        self.rpc_client
+            .request("EXPERIMENTAL_light_client_proof", Some(params))
+            .await
+            .map_err(Error::RpcError)
+            .and_then(|proof| {
+                serde_json::from_value::<LightClientProof>(proof)
+                    .map_err(Error::DeserializeResponseView)
+            })
     }
 
     /// Returns basic account information.
@@ -335,14 +1455,31 @@ impl NearClient {
     ///
     /// Returns a struct [`Account`] containing basic information about the specified Near account.
     pub async fn view_account(&self, account_id: &AccountId) -> Result<Account> {
+        self.view_account_at(account_id, Finality::Final.into())
+            .await
+    }
+
+    /// Returns basic account information at the specific block
+    ///
+    /// ## Arguments
+    ///
+    /// - `account_id` - The account ID [`AccountId`] for which to retrieve information.
+    /// - `block_reference` - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    pub async fn view_account_at(
+        &self,
+        account_id: &AccountId,
+        block_reference: BlockReference,
+    ) -> Result<Account> {
         self.rpc_client
             .request(
                 "query",
-                Some(json!({
-                    "request_type": "view_account",
-                    "finality": Finality::Final,
-                    "account_id": account_id,
-                })),
+                Some(query_params(
+                    json!({
+                        "request_type": "view_account",
+                        "account_id": account_id,
+                    }),
+                    block_reference,
+                )?),
             )
             .await
             .map_err(Error::ViewCall)
@@ -351,23 +1488,99 @@ impl NearClient {
             })
     }
 
+    /// Returns basic information of multiple accounts within a single batch request
+    ///
+    /// ## Arguments
+    ///
+    /// - `account_ids` - The account IDs [`AccountId`] for which to retrieve information.
+    ///
+    /// ## Returns
+    ///
+    /// Returns the [`Account`] results in the same order as the account IDs.
+    /// If the batch request fails, an error is returned for all of the accounts
+    pub async fn view_accounts(&self, account_ids: &[AccountId]) -> Result<Vec<Result<Account>>> {
+        let calls = account_ids
+            .iter()
+            .map(|account_id| {
+                Ok((
+                    "query",
+                    Some(query_params(
+                        json!({
+                            "request_type": "view_account",
+                            "account_id": account_id,
+                        }),
+                        Finality::Final.into(),
+                    )?),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(self
+            .rpc_client
+            .request_batch(calls)
+            .await
+            .map_err(Error::ViewCall)?
+            .into_iter()
+            .map(|resp| {
+                resp.map_err(Error::ViewCall).and_then(|it| {
+                    serde_json::from_value::<Account>(it).map_err(Error::DeserializeViewCall)
+                })
+            })
+            .collect())
+    }
+
+    /// Returns basic account information with the block, at which it was read
+    ///
+    /// ## Arguments
+    ///
+    /// - `account_id` - The account ID [`AccountId`] for which to retrieve information.
+    /// - `block_reference` - [`BlockReference`], it could be a block height, hash or [`Finality`]
+    ///
+    /// ## Returns
+    ///
+    /// Returns a struct [`ViewAccountResult`] containing
+    /// [`AccountView`](crate::near_primitives_light::views::AccountView),
+    /// the block hash and the block height
+    pub async fn view_account_detailed(
+        &self,
+        account_id: &AccountId,
+        block_reference: BlockReference,
+    ) -> Result<ViewAccountResult> {
+        self.rpc_client
+            .request(
+                "query",
+                Some(query_params(
+                    json!({
+                        "request_type": "view_account",
+                        "account_id": account_id,
+                    }),
+                    block_reference,
+                )?),
+            )
+            .await
+            .map_err(Error::ViewCall)
+            .and_then(|it| {
+                serde_json::from_value::<ViewAccountResult>(it).map_err(Error::DeserializeViewCall)
+            })
+    }
+
     /// Creates new access key on the specified account
     ///
     /// Arguments
     /// - signer - Transaction [`Signer`]
     /// - account_id - The user [`AccountId`] in a Near network
-    /// - new_account_pk - The new [`Ed25519PublicKey`]
+    /// - new_account_pk - The new [`NearPublicKey`]
     /// - permission - Granted permissions level for the new access key
     pub fn add_access_key<'a>(
         &'a self,
         signer: &'a Signer,
         account_id: &'a AccountId,
-        new_account_pk: Ed25519PublicKey,
+        new_account_pk: impl Into<NearPublicKey>,
         permission: AccessKeyPermission,
     ) -> FunctionCall {
         let info = TransactionInfo::new(self, signer, account_id);
         let actions = vec![AddKeyAction {
-            public_key: new_account_pk,
+            public_key: new_account_pk.into(),
             access_key: AccessKey {
                 nonce: rand::random::<u64>(),
                 permission,
@@ -377,57 +1590,344 @@ impl NearClient {
         FunctionCall::new(info, actions)
     }
 
+    /// Creates new function call access key on the specified account,
+    /// the key is allowed to call only the given methods of the receiver
+    ///
+    /// Arguments
+    /// - signer - Transaction [`Signer`]
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - new_account_pk - The new [`NearPublicKey`]
+    /// - receiver_id - The contract [`AccountId`], that the key is allowed to call
+    /// - method_names - Methods, that the key is allowed to call, if it's empty any method is allowed
+    /// - allowance - Amount of tokens, that the key is allowed to spend on gas, if it's empty the amount is unlimited
+    pub fn add_function_call_key<'a>(
+        &'a self,
+        signer: &'a Signer,
+        account_id: &'a AccountId,
+        new_account_pk: impl Into<NearPublicKey>,
+        receiver_id: AccountId,
+        method_names: Vec<String>,
+        allowance: Option<Balance>,
+    ) -> FunctionCall {
+        let permission = AccessKeyPermission::FunctionCall(FunctionCallPermission {
+            allowance,
+            receiver_id: receiver_id.to_string(),
+            method_names,
+        });
+        self.add_access_key(signer, account_id, new_account_pk, permission)
+    }
+
     /// Deletes an access key on the specified account
     ///
-    /// Arguments
-    /// - signer - Transaction [`Signer`]
-    /// - account_id - The user [`AccountId`] in a Near network
-    /// - public_key - The [`Ed25519PublicKey`] to be deleted from users access keys
-    pub fn delete_access_key<'a>(
-        &'a self,
-        signer: &'a Signer,
-        account_id: &'a AccountId,
-        public_key: Ed25519PublicKey,
-    ) -> FunctionCall {
-        let info = TransactionInfo::new(self, signer, account_id);
-        let actions = vec![DeleteKeyAction { public_key }.into()];
-        FunctionCall::new(info, actions)
+    /// Arguments
+    /// - signer - Transaction [`Signer`]
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - public_key - The [`NearPublicKey`] to be deleted from users access keys
+    pub fn delete_access_key<'a>(
+        &'a self,
+        signer: &'a Signer,
+        account_id: &'a AccountId,
+        public_key: impl Into<NearPublicKey>,
+    ) -> FunctionCall {
+        let info = TransactionInfo::new(self, signer, account_id);
+        let actions = vec![DeleteKeyAction {
+            public_key: public_key.into(),
+        }
+        .into()];
+        FunctionCall::new(info, actions)
+    }
+
+    /// Replaces a full access key with a new one in a single transaction,
+    /// so the account never stays without a key or with both keys
+    ///
+    /// Arguments
+    /// - signer - Transaction [`Signer`]
+    /// - account_id - The user [`AccountId`] in a Near network
+    /// - old_pk - The [`NearPublicKey`] to be deleted from users access keys
+    /// - new_pk - The new full access [`NearPublicKey`]
+    pub fn rotate_full_access_key<'a>(
+        &'a self,
+        signer: &'a Signer,
+        account_id: &'a AccountId,
+        old_pk: impl Into<NearPublicKey>,
+        new_pk: impl Into<NearPublicKey>,
+    ) -> FunctionCall {
+        let info = TransactionInfo::new(self, signer, account_id);
+        let actions = vec![
+            AddKeyAction {
+                public_key: new_pk.into(),
+                access_key: AccessKey {
+                    nonce: rand::random::<u64>(),
+                    permission: AccessKeyPermission::FullAccess,
+                },
+            }
+            .into(),
+            DeleteKeyAction {
+                public_key: old_pk.into(),
+            }
+            .into(),
+        ];
+        FunctionCall::new(info, actions)
+    }
+
+    /// Execute a transaction with a function call to the smart contract
+    ///
+    /// Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - method - Function that is declared in a smart contract (Arguments fir function call provided later in a [`FunctionCallBuilder`])
+    pub fn function_call<'a>(
+        &'a self,
+        signer: &'a Signer,
+        contract_id: &'a AccountId,
+        method: &'a str,
+    ) -> FunctionCallBuilder {
+        let transaction_info = TransactionInfo::new(self, signer, contract_id);
+        FunctionCallBuilder::new(transaction_info, method)
+    }
+
+    /// Deploys contract code to the chain
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - wasm - Actually a compiled code
+    pub fn deploy_contract<'a>(
+        &'a self,
+        signer: &'a Signer,
+        contract_id: &'a AccountId,
+        wasm: Vec<u8>,
+    ) -> FunctionCall {
+        FunctionCall::new(
+            TransactionInfo::new(self, signer, contract_id),
+            vec![Action::from(DeployContractAction { code: wasm })],
+        )
+    }
+
+    /// Deploys contract code to the chain and calls the initialization method
+    /// in the same transaction, so the contract can't be called uninitialized
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - wasm - Actually a compiled code
+    /// - init_method - The initialization method of the contract
+    /// - args - Arguments of the initialization method
+    /// - gas - Amount of gas that will be hold for the initialization
+    /// - deposit - Amount of tokens attached to the initialization
+    #[allow(clippy::too_many_arguments, clippy::result_large_err)]
+    pub fn deploy_and_init<'a>(
+        &'a self,
+        signer: &'a Signer,
+        contract_id: &'a AccountId,
+        wasm: Vec<u8>,
+        init_method: &'static str,
+        args: Value,
+        gas: Gas,
+        deposit: Balance,
+    ) -> Result<FunctionCall<'a>> {
+        Ok(FunctionCall::new(
+            TransactionInfo::new(self, signer, contract_id),
+            vec![
+                Action::from(DeployContractAction { code: wasm }),
+                Action::from(FunctionCallAction {
+                    method_name: init_method.to_string(),
+                    args: serialize_arguments(Some(args))?,
+                    gas,
+                    deposit,
+                }),
+            ],
+        ))
+    }
+
+    /// Deploys contract code to the chain and verifies
+    /// that the on-chain code hash matches the deployed code
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - contract_id - The [`AccountId`] where smart contract is located
+    /// - wasm - Actually a compiled code
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::CodeHashMismatch`] if the deployed code hash differs from the local one
+    pub async fn deploy_and_verify(
+        &self,
+        signer: &Signer,
+        contract_id: &AccountId,
+        wasm: Vec<u8>,
+    ) -> Result<Output> {
+        let expected = code_hash(&wasm);
+        let output = self
+            .deploy_contract(signer, contract_id, wasm)
+            .commit(Finality::Final)
+            .await?;
+
+        let actual = self
+            .view_contract_code(contract_id, Finality::None)
+            .await?
+            .hash;
+
+        if actual != expected {
+            return Err(Error::CodeHashMismatch { expected, actual });
+        }
+
+        Ok(output)
+    }
+
+    /// Creates and signs a transaction without any network request,
+    /// so it could be done on an air-gapped machine.
+    /// The transaction could be broadcasted later with [`broadcast_signed`](NearClient::broadcast_signed())
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - receiver_id - The [`AccountId`], that receives all of the actions
+    /// - actions - Transaction actions
+    /// - nonce - The access key nonce, it should be greater than the current one
+    /// - block_hash - Recent block [`CryptoHash`], the transaction expires in about a day after the block
+    pub fn sign_transaction_offline(
+        signer: &Signer,
+        receiver_id: AccountId,
+        actions: Vec<Action>,
+        nonce: Nonce,
+        block_hash: CryptoHash,
+    ) -> SignedTransaction {
+        let transaction = Transaction {
+            signer_id: signer.account().clone(),
+            public_key: signer.public_key().into(),
+            nonce,
+            receiver_id,
+            block_hash,
+            actions,
+        };
+        sign_transaction(signer, transaction)
+    }
+
+    /// Sends a signed transaction and waits until transaction is fully complete
+    ///
+    /// ## Arguments
+    ///
+    /// - transaction - [`SignedTransaction`], e.g. created with [`sign_transaction_offline`](NearClient::sign_transaction_offline())
+    pub async fn broadcast_signed(&self, transaction: &SignedTransaction) -> Result<Output> {
+        self.broadcast("broadcast_tx_commit", transaction)
+            .await
+            .and_then(|execution_outcome| {
+                serde_json::from_value::<FinalExecutionOutcomeView>(execution_outcome)
+                    .map_err(Error::DeserializeExecutionOutcome)
+            })
+            .and_then(into_output)
+    }
+
+    /// Sends a signed transaction and immediately returns transaction hash
+    ///
+    /// ## Arguments
+    ///
+    /// - transaction - [`SignedTransaction`], e.g. created with [`sign_transaction_offline`](NearClient::sign_transaction_offline())
+    pub async fn broadcast_signed_async(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<CryptoHash> {
+        self.broadcast("broadcast_tx_async", transaction)
+            .await
+            .and_then(|id| {
+                serde_json::from_value::<CryptoHash>(id).map_err(Error::DeserializeTransactionId)
+            })
+    }
+
+    async fn broadcast(
+        &self,
+        transaction_type: &'static str,
+        transaction: &SignedTransaction,
+    ) -> Result<Value> {
+        let transaction = BASE64_STANDARD_NO_PAD
+            .encode(borsh::to_vec(transaction).map_err(Error::TxSerialization)?);
+        self.rpc_client
+            .request(transaction_type, Some(json!(vec![transaction])))
+            .await
+            .map_err(transaction_error)
+    }
+
+    /// Creates a delegate action, that is signed by the user and
+    /// could be relayed with [`relay_delegate`](NearClient::relay_delegate()), so the relayer pays for gas.
+    /// It's a meta transaction, see [NEP-366](https://github.com/near/NEPs/blob/master/neps/nep-0366.md)
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - [`Signer`] of the user
+    /// - receiver_id - The [`AccountId`], that receives all of the actions
+    /// - actions - Delegated actions, they can't contain another delegate action
+    /// - max_block_height - The block height, after which the delegate action is invalid
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::NestedDelegateAction`], if one of the actions is a delegate action
+    #[allow(clippy::result_large_err)]
+    pub fn build_delegate_action(
+        signer: &Signer,
+        receiver_id: AccountId,
+        actions: Vec<Action>,
+        max_block_height: BlockHeight,
+    ) -> Result<SignedDelegateAction> {
+        let actions = actions
+            .into_iter()
+            .map(NonDelegateAction::try_from)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| Error::NestedDelegateAction)?;
+
+        let nonce = signer.reserve_nonce();
+        let delegate_action = DelegateAction {
+            sender_id: signer.account().clone(),
+            receiver_id,
+            actions,
+            nonce,
+            max_block_height,
+            public_key: *signer.public_key(),
+        };
+        let signature = signer.sign(&delegate_action.get_nep461_hash());
+
+        Ok(SignedDelegateAction {
+            delegate_action,
+            signature,
+        })
     }
 
-    /// Execute a transaction with a function call to the smart contract
+    /// Wraps the signed delegate action into a transaction of the relayer
     ///
-    /// Arguments
+    /// ## Arguments
     ///
-    /// - signer - Transaction [`Signer`]
-    /// - contract_id - The [`AccountId`] where smart contract is located
-    /// - method - Function that is declared in a smart contract (Arguments fir function call provided later in a [`FunctionCallBuilder`])
-    pub fn function_call<'a>(
+    /// - relayer - [`Signer`] of the relayer, that pays for gas
+    /// - signed_delegate - [`SignedDelegateAction`], that is created with [`build_delegate_action`](NearClient::build_delegate_action())
+    pub fn relay_delegate<'a>(
         &'a self,
-        signer: &'a Signer,
-        contract_id: &'a AccountId,
-        method: &'static str,
-    ) -> FunctionCallBuilder {
-        let transaction_info = TransactionInfo::new(self, signer, contract_id);
-        FunctionCallBuilder::new(transaction_info, method)
+        relayer: &'a Signer,
+        signed_delegate: &'a SignedDelegateAction,
+    ) -> FunctionCall {
+        FunctionCall::new(
+            TransactionInfo::new(self, relayer, &signed_delegate.delegate_action.sender_id),
+            vec![Action::Delegate(signed_delegate.clone())],
+        )
     }
 
-    /// Deploys contract code to the chain
+    /// Creates a transaction, that contains multiple actions.
+    /// All of the actions are executed atomically, with a single nonce and block hash.
+    /// The actions are validated against the mainnet [`ActionsLimits`] before the submission,
+    /// other limits could be set with [`TransactionBuilder::limits`]
     ///
     /// ## Arguments
     ///
     /// - signer - Transaction [`Signer`]
-    /// - contract_id - The [`AccountId`] where smart contract is located
-    /// - wasm - Actually a compiled code
-    pub fn deploy_contract<'a>(
+    /// - receiver_id - The [`AccountId`], that receives all of the actions
+    pub fn batch<'a>(
         &'a self,
         signer: &'a Signer,
-        contract_id: &'a AccountId,
-        wasm: Vec<u8>,
-    ) -> FunctionCall {
-        FunctionCall::new(
-            TransactionInfo::new(self, signer, contract_id),
-            vec![Action::from(DeployContractAction { code: wasm })],
-        )
+        receiver_id: &'a AccountId,
+    ) -> TransactionBuilder<'a> {
+        TransactionBuilder::new(TransactionInfo::new(self, signer, receiver_id))
     }
 
     /// Creates account
@@ -436,20 +1936,20 @@ impl NearClient {
     ///
     /// - signer - Transaction [`Signer`]
     /// - new_account_id - The new [`AccountId`]
-    /// - new_account_pk - The new [`Ed25519PublicKey`]
+    /// - new_account_pk - The new [`NearPublicKey`]
     /// - amount - Initial balance of that account, could be zero
     pub fn create_account<'a>(
         &'a self,
         signer: &'a Signer,
         new_account_id: &'a AccountId,
-        new_account_pk: Ed25519PublicKey,
+        new_account_pk: impl Into<NearPublicKey>,
         amount: Balance,
     ) -> FunctionCall {
         let info = TransactionInfo::new(self, signer, new_account_id);
         let actions = vec![
             CreateAccountAction {}.into(),
             AddKeyAction {
-                public_key: new_account_pk,
+                public_key: new_account_pk.into(),
                 access_key: AccessKey {
                     nonce: 0,
                     permission: AccessKeyPermission::FullAccess,
@@ -484,6 +1984,72 @@ impl NearClient {
         FunctionCall::new(info, actions)
     }
 
+    /// Deletes account, but before that checks locally
+    /// that the account could be deleted by the protocol
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - account_id - The [`AccountId`] that we own and want to delete
+    /// - beneficiary_acc_id - Where to return a founds from the deleted account
+    ///
+    /// ## Errors
+    ///
+    /// - [`Error::InvalidBeneficiary`] - if the beneficiary is the deleted account itself
+    /// - [`Error::AccountIsStaking`] - if the account has locked balance
+    /// - [`Error::AccountStateTooLarge`] - if the account storage is too large to be deleted
+    pub async fn delete_account_checked<'a>(
+        &'a self,
+        signer: &'a Signer,
+        account_id: &'a AccountId,
+        beneficiary_acc_id: &'a AccountId,
+    ) -> Result<FunctionCall<'a>> {
+        if account_id == beneficiary_acc_id {
+            return Err(Error::InvalidBeneficiary(beneficiary_acc_id.clone()));
+        }
+
+        let account = self.view_account(account_id).await?;
+
+        if account.locked() > 0 {
+            return Err(Error::AccountIsStaking(account_id.clone()));
+        }
+
+        if account.storage_usage() > MAX_ACCOUNT_DELETION_STORAGE_USAGE {
+            return Err(Error::AccountStateTooLarge {
+                account_id: account_id.clone(),
+                storage_usage: account.storage_usage(),
+            });
+        }
+
+        Ok(self.delete_account(signer, account_id, beneficiary_acc_id))
+    }
+
+    /// Stakes Near tokens, the staked amount becomes locked on the account.
+    /// To unstake the tokens call it with a zero amount
+    ///
+    /// ## Arguments
+    ///
+    /// - signer - Transaction [`Signer`]
+    /// - account_id - The [`AccountId`], that stakes tokens
+    /// - amount - The total amount of tokens to stake
+    /// - public_key - The validator [`NearPublicKey`]
+    pub fn stake<'a>(
+        &'a self,
+        signer: &'a Signer,
+        account_id: &'a AccountId,
+        amount: Balance,
+        public_key: impl Into<NearPublicKey>,
+    ) -> FunctionCall {
+        let info = TransactionInfo::new(self, signer, account_id);
+        let actions = vec![StakeAction {
+            stake: amount,
+            public_key: public_key.into(),
+        }
+        .into()];
+
+        FunctionCall::new(info, actions)
+    }
+
     /// Sends Near tokens from one account to another.
     ///
     /// ## Arguments
@@ -500,24 +2066,178 @@ impl NearClient {
         &'a self,
         signer: &'a Signer,
         receiver_id: &'a AccountId,
-        deposit: Balance,
+        deposit: impl Into<Balance>,
     ) -> FunctionCall {
         let info = TransactionInfo::new(self, signer, receiver_id);
-        let actions = vec![TransferAction { deposit }.into()];
+        let actions = vec![TransferAction {
+            deposit: deposit.into(),
+        }
+        .into()];
 
         FunctionCall::new(info, actions)
     }
+
+    /// Sends Near tokens from one account to another,
+    /// but before that checks that the deposit isn't zero
+    ///
+    /// ## Arguments
+    ///
+    /// - `signer` - The account ID of the sender and transaction [`Signer`]
+    /// - `receiver_id` - The account ID of the receiver.
+    /// - `deposit` - The amount of Near tokens to send.
+    ///
+    /// ## Errors
+    ///
+    /// - [`Error::ZeroTransfer`] - if the deposit is zero
+    #[allow(clippy::result_large_err)]
+    pub fn send_checked<'a>(
+        &'a self,
+        signer: &'a Signer,
+        receiver_id: &'a AccountId,
+        deposit: impl Into<Balance>,
+    ) -> Result<FunctionCall> {
+        let deposit = deposit.into();
+        if deposit == 0 {
+            return Err(Error::ZeroTransfer(receiver_id.clone()));
+        }
+
+        Ok(self.send(signer, receiver_id, deposit))
+    }
+}
+
+struct PageState<T> {
+    from_index: u64,
+    page: std::vec::IntoIter<T>,
+    finished: bool,
+}
+
+/// Arguments of a paginated contract view method, see [`NearClient::view_paginated_with`].
+/// By default the method is called with `{"from_index": "0", "limit": page_size}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    page_size: u64,
+    from_index_arg: String,
+    limit_arg: String,
+    numeric_index: bool,
+}
+
+impl Pagination {
+    /// Creates pagination with the default argument names
+    ///
+    /// Arguments
+    ///
+    /// - page_size - Amount of items requested per call
+    pub fn new(page_size: u64) -> Self {
+        Self {
+            page_size,
+            from_index_arg: "from_index".to_owned(),
+            limit_arg: "limit".to_owned(),
+            numeric_index: false,
+        }
+    }
+
+    /// Set a name of the argument with an index of the first item
+    pub fn from_index_arg(mut self, name: &str) -> Self {
+        self.from_index_arg = name.to_owned();
+        self
+    }
+
+    /// Set a name of the argument with a page size
+    pub fn limit_arg(mut self, name: &str) -> Self {
+        self.limit_arg = name.to_owned();
+        self
+    }
+
+    /// Pass an index as a JSON number, for the methods that accept `u64` instead of `U128`
+    pub const fn numeric_index(mut self) -> Self {
+        self.numeric_index = true;
+        self
+    }
+
+    fn args(&self, from_index: u64) -> Value {
+        let from_index = if self.numeric_index {
+            json!(from_index)
+        } else {
+            json!(from_index.to_string())
+        };
+
+        let mut args = serde_json::Map::new();
+        args.insert(self.from_index_arg.clone(), from_index);
+        args.insert(self.limit_arg.clone(), json!(self.page_size));
+        Value::Object(args)
+    }
+}
+
+/// Sets a timeout of a single request, e.g. of a [`view`](NearClient::view()) call,
+/// without creating a client with another timeout.
+/// It's implemented for each future, that returns the crate [`Result`]
+///
+/// ```no_run
+/// use near_client::prelude::*;
+/// use std::{str::FromStr, time::Duration};
+///
+/// # async fn run(client: NearClient) -> Result<(), near_client::Error> {
+/// let contract_id = AccountId::from_str("contract.near").unwrap();
+/// let version = client
+///     .view::<String>(&contract_id, Finality::Final, "version", None)
+///     .timeout(Duration::from_secs(1))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait RequestTimeout<T>: Future<Output = Result<T>> + Sized {
+    /// Fails with [`Error::RequestTimeout`], if the request isn't finished in time.
+    /// It overrides the client timeout for this request only
+    ///
+    /// Arguments
+    ///
+    /// - timeout - Timeout of the request
+    fn timeout(self, timeout: Duration) -> Timeout<Self> {
+        Timeout {
+            request: Box::pin(self),
+            delay: Delay::new(timeout),
+            timeout,
+        }
+    }
+}
+
+impl<T, F: Future<Output = Result<T>>> RequestTimeout<T> for F {}
+
+/// A request with a timeout, see [`RequestTimeout`]
+#[doc(hidden)]
+pub struct Timeout<F> {
+    request: std::pin::Pin<Box<F>>,
+    delay: Delay,
+    timeout: Duration,
+}
+
+impl<T, F: Future<Output = Result<T>>> Future for Timeout<F> {
+    type Output = Result<T>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if let std::task::Poll::Ready(output) = self.request.as_mut().poll(cx) {
+            return std::task::Poll::Ready(output);
+        }
+
+        let timeout = self.timeout;
+        std::pin::Pin::new(&mut self.delay)
+            .poll(cx)
+            .map(|_| Err(Error::RequestTimeout(timeout)))
+    }
 }
 
 /// Output of a view contract call
 /// Contains the return data and logs
 #[derive(Debug)]
-pub struct ViewOutput<T: DeserializeOwned> {
+pub struct ViewOutput<T> {
     logs: Vec<String>,
     data: T,
 }
 
-impl<T: DeserializeOwned> ViewOutput<T> {
+impl<T> ViewOutput<T> {
     /// Logs from view call
     pub fn logs(&self) -> Vec<String> {
         self.logs.clone()
@@ -529,7 +2249,14 @@ impl<T: DeserializeOwned> ViewOutput<T> {
     }
 }
 
-impl<T: DeserializeOwned> Deref for ViewOutput<T> {
+impl ViewOutput<Vec<u8>> {
+    /// Return the undecoded bytes of a [`view_raw`](NearClient::view_raw()) call result
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<T> Deref for ViewOutput<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -537,7 +2264,7 @@ impl<T: DeserializeOwned> Deref for ViewOutput<T> {
     }
 }
 
-impl<T: DeserializeOwned> DerefMut for ViewOutput<T> {
+impl<T> DerefMut for ViewOutput<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.data
     }
@@ -549,6 +2276,10 @@ pub struct Output {
     transaction: ExecutionOutcomeWithIdView,
     logs: Vec<String>,
     data: Vec<u8>,
+    total_gas_burnt: Gas,
+    total_tokens_burnt: Balance,
+    gas_profile: Option<Vec<CostGasUsed>>,
+    finished: bool,
 }
 
 impl Output {
@@ -565,17 +2296,58 @@ impl Output {
         self.transaction.id
     }
 
-    /// Amount of gas that was burnt during transaction execution
+    /// Amount of gas that was burnt during transaction execution.
+    /// Doesn't include gas burnt by the receipts, see [`total_gas_burnt`](Output::total_gas_burnt())
     pub const fn gas_burnt(&self) -> Gas {
         self.transaction.outcome.gas_burnt
     }
 
-    /// Logs that smart contract produced
+    /// Amount of gas that was burnt by the transaction and all of its receipts
+    pub const fn total_gas_burnt(&self) -> Gas {
+        self.total_gas_burnt
+    }
+
+    /// Execution metadata of the transaction outcome
+    pub const fn metadata(&self) -> &ExecutionMetadataView {
+        &self.transaction.outcome.metadata
+    }
+
+    /// Gas costs of the transaction and all of its receipts, broken down per cost.
+    /// Returns [`None`], if the node didn't provide the profile
+    pub fn gas_profile(&self) -> Option<&[CostGasUsed]> {
+        self.gas_profile.as_deref()
+    }
+
+    /// Amount of tokens that was burnt by the transaction and all of its receipts
+    pub const fn total_tokens_burnt(&self) -> Balance {
+        self.total_tokens_burnt
+    }
+
+    /// Logs from the transaction and all of its receipts, in order of execution
     pub fn logs(&self) -> Vec<String> {
         self.logs.clone()
     }
 }
 
+impl std::fmt::Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The output is created only for a successful transaction,
+        // but the transaction could be still executing
+        let status = if self.finished {
+            "succeeded"
+        } else {
+            "started"
+        };
+        write!(
+            f,
+            "Transaction {}: {status}, gas burnt: {}, logs: {}",
+            self.id(),
+            gas_to_human(self.total_gas_burnt),
+            self.logs.len()
+        )
+    }
+}
+
 #[doc(hidden)]
 pub struct FunctionCallBuilder<'a> {
     info: TransactionInfo<'a>,
@@ -583,51 +2355,229 @@ pub struct FunctionCallBuilder<'a> {
     gas: Gas,
     args: Option<Value>,
     retry: Retry,
+    retry_backoff: Option<Duration>,
+    block_hash: Option<CryptoHash>,
+    timeout: Option<Duration>,
     method_name: &'a str,
 }
 
-impl<'a> FunctionCallBuilder<'a> {
-    fn new(info: TransactionInfo<'a>, method_name: &'a str) -> Self {
+impl<'a> FunctionCallBuilder<'a> {
+    fn new(info: TransactionInfo<'a>, method_name: &'a str) -> Self {
+        Self {
+            info,
+            method_name,
+            gas: Default::default(),
+            args: Default::default(),
+            deposit: Default::default(),
+            retry: Default::default(),
+            retry_backoff: Default::default(),
+            block_hash: Default::default(),
+            timeout: Default::default(),
+        }
+    }
+
+    /// Amount of tokens attached to the call, either a [`Balance`] or a [`NearToken`](crate::NearToken)
+    pub fn deposit(mut self, deposit: impl Into<Balance>) -> Self {
+        self.deposit = deposit.into();
+        self
+    }
+
+    /// Amount of gas that will be hold for function execution
+    pub const fn gas(mut self, gas: Gas) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn args(mut self, args: Value) -> Self {
+        self.args = Some(args);
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> Result<FunctionCall<'a>> {
+        let action = Action::from(FunctionCallAction {
+            method_name: self.method_name.to_string(),
+            args: serialize_arguments(self.args)?,
+            gas: self.gas,
+            deposit: self.deposit,
+        });
+
+        Ok(FunctionCall {
+            info: self.info,
+            actions: vec![action],
+            retry: self.retry,
+            retry_backoff: self.retry_backoff,
+            block_hash: self.block_hash,
+            timeout: self.timeout,
+        })
+    }
+
+    /// Set [`Retry`] strategy
+    pub const fn retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set a delay between retries, that are caused by transient network errors
+    pub const fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = Some(backoff);
+        self
+    }
+
+    /// Sign a transaction with the given block hash, instead of querying the most recent one.
+    /// It saves a network request, if the block hash is already known
+    ///
+    /// ## Arguments
+    ///
+    /// - **block_hash** - Block [`CryptoHash`]
+    pub const fn block_hash(mut self, block_hash: CryptoHash) -> Self {
+        self.block_hash = Some(block_hash);
+        self
+    }
+
+    /// Set a timeout of the transaction request, that overrides the client timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends a transaction and waits until transaction is fully complete. (Has a 10 second timeout)
+    /// Also, possible that an output data will be empty if the transaction is still executing
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    pub async fn commit(self, finality: Finality) -> Result<Output> {
+        let call = self.build()?;
+        call.commit(finality).await
+    }
+
+    /// Sends a transaction and waits until it's complete or the `cancel` future completes,
+    /// see [`FunctionCall::commit_with_cancel`]
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    /// - **cancel** - A future, that cancels the commit when it's completed
+    pub async fn commit_with_cancel(
+        self,
+        finality: Finality,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Output> {
+        let call = self.build()?;
+        call.commit_with_cancel(finality, cancel).await
+    }
+
+    /// Sends a transaction and immediately returns transaction hash.
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    pub async fn commit_async(self, finality: Finality) -> Result<CryptoHash> {
+        let call = self.build()?;
+        call.commit_async(finality).await
+    }
+}
+
+#[doc(hidden)]
+pub struct TransactionBuilder<'a> {
+    info: TransactionInfo<'a>,
+    actions: Result<Vec<Action>>,
+    retry: Retry,
+    limits: ActionsLimits,
+}
+
+impl<'a> TransactionBuilder<'a> {
+    fn new(info: TransactionInfo<'a>) -> Self {
         Self {
             info,
-            method_name,
-            gas: Default::default(),
-            args: Default::default(),
-            deposit: Default::default(),
+            actions: Ok(vec![]),
             retry: Default::default(),
+            limits: Default::default(),
         }
     }
 
-    pub const fn deposit(mut self, deposit: Balance) -> Self {
-        self.deposit = deposit;
-        self
+    /// Creates the receiver account, should be the first action
+    pub fn create_account(self) -> Self {
+        self.action(CreateAccountAction {}.into())
     }
 
-    /// Amount of gas that will be hold for function execution
-    pub const fn gas(mut self, gas: Gas) -> Self {
-        self.gas = gas;
-        self
+    /// Deploys a contract code to the receiver account
+    pub fn deploy(self, wasm: Vec<u8>) -> Self {
+        self.action(DeployContractAction { code: wasm }.into())
     }
 
-    pub fn args(mut self, args: Value) -> Self {
-        self.args = Some(args);
+    /// Calls a contract method of the receiver account
+    ///
+    /// ## Arguments
+    ///
+    /// - **method** - Function that is declared in a smart contract
+    /// - **args** - Function arguments, could be empty
+    /// - **gas** - Amount of gas that will be hold for function execution
+    /// - **deposit** - Amount of tokens attached to the call
+    pub fn function_call(
+        mut self,
+        method: &str,
+        args: Option<Value>,
+        gas: Gas,
+        deposit: Balance,
+    ) -> Self {
+        self.actions = self.actions.and_then(|mut actions| {
+            actions.push(
+                FunctionCallAction {
+                    method_name: method.to_string(),
+                    args: serialize_arguments(args)?,
+                    gas,
+                    deposit,
+                }
+                .into(),
+            );
+            Ok(actions)
+        });
         self
     }
 
-    #[allow(clippy::result_large_err)]
-    pub fn build(self) -> Result<FunctionCall<'a>> {
-        let action = Action::from(FunctionCallAction {
-            method_name: self.method_name.to_string(),
-            args: serialize_arguments(self.args)?,
-            gas: self.gas,
-            deposit: self.deposit,
-        });
+    /// Transfers tokens to the receiver account
+    pub fn transfer(self, deposit: impl Into<Balance>) -> Self {
+        self.action(
+            TransferAction {
+                deposit: deposit.into(),
+            }
+            .into(),
+        )
+    }
 
-        Ok(FunctionCall {
-            info: self.info,
-            actions: vec![action],
-            retry: self.retry,
-        })
+    /// Adds an access key to the receiver account
+    pub fn add_key(
+        self,
+        public_key: impl Into<NearPublicKey>,
+        permission: AccessKeyPermission,
+    ) -> Self {
+        self.action(
+            AddKeyAction {
+                public_key: public_key.into(),
+                access_key: AccessKey {
+                    nonce: 0,
+                    permission,
+                },
+            }
+            .into(),
+        )
+    }
+
+    /// Deletes an access key of the receiver account
+    pub fn delete_key(self, public_key: impl Into<NearPublicKey>) -> Self {
+        self.action(
+            DeleteKeyAction {
+                public_key: public_key.into(),
+            }
+            .into(),
+        )
+    }
+
+    /// Deletes the receiver account, should be the last action
+    pub fn delete_account(self, beneficiary_id: AccountId) -> Self {
+        self.action(DeleteAccountAction { beneficiary_id }.into())
     }
 
     /// Set [`Retry`] strategy
@@ -636,6 +2586,26 @@ impl<'a> FunctionCallBuilder<'a> {
         self
     }
 
+    /// Set [`ActionsLimits`], that the actions are validated against, the mainnet limits are used by default.
+    /// The limits of the network could be queried with [`actions_limits`](NearClient::actions_limits())
+    pub const fn limits(mut self, limits: ActionsLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Creates a transaction with all of the added actions
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::BatchLimitsExceeded`], if total prepaid gas or number of actions exceeds the limits
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> Result<FunctionCall<'a>> {
+        let limits = self.limits;
+        FunctionCall::new(self.info, self.actions?)
+            .retry(self.retry)
+            .validate(&limits)
+    }
+
     /// Sends a transaction and waits until transaction is fully complete. (Has a 10 second timeout)
     /// Also, possible that an output data will be empty if the transaction is still executing
     ///
@@ -647,6 +2617,22 @@ impl<'a> FunctionCallBuilder<'a> {
         call.commit(finality).await
     }
 
+    /// Sends a transaction and waits until it's complete or the `cancel` future completes,
+    /// see [`FunctionCall::commit_with_cancel`]
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    /// - **cancel** - A future, that cancels the commit when it's completed
+    pub async fn commit_with_cancel(
+        self,
+        finality: Finality,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Output> {
+        let call = self.build()?;
+        call.commit_with_cancel(finality, cancel).await
+    }
+
     /// Sends a transaction and immediately returns transaction hash.
     ///
     /// ## Arguments
@@ -656,25 +2642,110 @@ impl<'a> FunctionCallBuilder<'a> {
         let call = self.build()?;
         call.commit_async(finality).await
     }
+
+    fn action(mut self, action: Action) -> Self {
+        if let Ok(actions) = &mut self.actions {
+            actions.push(action);
+        }
+        self
+    }
 }
 
 /// Tells the **client** to execute transaction one more time if it's failed.
-/// > It's only happens during **InvalidNonce** error.
+/// > It's only happens during **InvalidNonce** or **Expired** error,
+/// > or if the request failed because of a transient network error, like a timeout or 5xx response.
 ///
 /// - NONE - default value, transaction executes once
 /// - ONCE - retry once
 /// - TWICE - retry two times
+/// - Backoff - retry with an exponential delay between attempts
+///
+/// > **Breaking change:** `Retry` isn't `#[repr(usize)]` anymore, because the `Backoff` variant
+/// > carries data, so `Retry::ONCE as usize` doesn't compile.
+/// > Use [`Retry::attempts`] to get the total number of executions instead.
 ///
-#[repr(usize)]
+/// ```
+/// use near_client::prelude::Retry;
+///
+/// assert_eq!(Retry::NONE.attempts(), 1);
+/// assert_eq!(Retry::ONCE.attempts(), 2);
+/// assert_eq!(Retry::TWICE.attempts(), 3);
+/// ```
 #[derive(Debug, Default, Clone, Copy)]
 pub enum Retry {
     /// Executes once, basically no retry
     #[default]
-    NONE = 1,
-    /// If **InvalidNonce** error received try to execute one more time
-    ONCE = 2,
-    /// If **InvalidNonce** error received try to execute two times
-    TWICE = 3,
+    NONE,
+    /// If **InvalidNonce**, **Expired** or a transient network error received try to execute one more time
+    ONCE,
+    /// If **InvalidNonce**, **Expired** or a transient network error received try to execute two times
+    TWICE,
+    /// If **InvalidNonce**, **Expired** or a transient network error received
+    /// try to execute until `max_attempts` is reached,
+    /// the `n`-th retry is delayed for `base * factor^n`, but not longer than 10 minutes.
+    /// A transaction fails with [`Error::InvalidRetryFactor`],
+    /// if the `factor` isn't finite or is less than 1
+    Backoff {
+        /// Total number of executions, including the first one
+        max_attempts: usize,
+        /// Delay before the first retry
+        base: Duration,
+        /// Multiplier of the delay for each next retry
+        factor: f64,
+        /// Randomizes each delay in range `[delay / 2, delay]`,
+        /// so the clients don't retry simultaneously
+        jitter: bool,
+    },
+}
+
+impl Retry {
+    /// Total number of executions, including the first one.
+    /// It's the same number, as the discriminant of the discrete variants used to be
+    pub const fn attempts(&self) -> usize {
+        match self {
+            Self::NONE => 1,
+            Self::ONCE => 2,
+            Self::TWICE => 3,
+            Self::Backoff { max_attempts, .. } => *max_attempts,
+        }
+    }
+
+    /// Rejects the backoff factors, that make the delay shrink, negative or undefined
+    #[allow(clippy::result_large_err)]
+    fn validate(&self) -> Result<()> {
+        match self {
+            Self::Backoff { factor, .. } if !factor.is_finite() || *factor < 1.0 => {
+                Err(Error::InvalidRetryFactor(*factor))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Delay before the `retry`-th retry, starting from zero.
+    /// Discrete strategies retry immediately
+    fn delay(&self, retry: usize) -> Option<Duration> {
+        let Self::Backoff {
+            base,
+            factor,
+            jitter,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        // The multiplier is saturated, so a zero base stays zero instead of NaN
+        let multiplier = factor
+            .powi(retry.try_into().unwrap_or(i32::MAX))
+            .min(f64::MAX);
+        let delay = Duration::try_from_secs_f64(base.as_secs_f64() * multiplier)
+            .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY));
+        if *jitter {
+            Some(delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0)))
+        } else {
+            Some(delay)
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -682,6 +2753,9 @@ pub struct FunctionCall<'a> {
     info: TransactionInfo<'a>,
     actions: Vec<Action>,
     retry: Retry,
+    retry_backoff: Option<Duration>,
+    block_hash: Option<CryptoHash>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> FunctionCall<'a> {
@@ -703,6 +2777,46 @@ impl<'a> FunctionCall<'a> {
         proceed_outcome(self.info.signer(), execution_outcome)
     }
 
+    /// The same as [`commit`](FunctionCall::commit()), but stops waiting for the transaction
+    /// as soon as the `cancel` future completes, for example when a user leaves the page.
+    /// The transaction, that is already sent, could be still executed by the network,
+    /// its status could be checked with [`view_transaction`](NearClient::view_transaction())
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    /// - **cancel** - A future, that cancels the commit when it's completed
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::Cancelled`], if the `cancel` future completes first
+    pub async fn commit_with_cancel(
+        self,
+        finality: Finality,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Output> {
+        let commit = self.commit(finality);
+        futures::pin_mut!(commit, cancel);
+
+        match future::select(commit, cancel).await {
+            Either::Left((output, _)) => output,
+            Either::Right(_) => Err(Error::Cancelled),
+        }
+    }
+
+    /// Estimates an amount of gas, that the transaction and all of its receipts burn.
+    /// Near doesn't support a dry-run, so the transaction is **executed** and
+    /// the burnt gas is taken from the outcome. Use it against a sandbox or a forked network
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`]
+    pub async fn estimate_gas(self, finality: Finality) -> Result<Gas> {
+        self.commit(finality)
+            .await
+            .map(|output| output.total_gas_burnt())
+    }
+
     /// Sends a transaction and immediately returns transaction hash.
     ///
     /// ## Arguments
@@ -716,12 +2830,78 @@ impl<'a> FunctionCall<'a> {
             })
     }
 
+    /// Signs a transaction without sending it, so the transaction hash is known before the broadcast,
+    /// it could be read with [`SignedTransaction::get_hash`]. The transaction could be tracked
+    /// with [`view_transaction`](NearClient::view_transaction()) even if a broadcast response is lost.
+    /// Send it with [`broadcast_signed`](NearClient::broadcast_signed()) or
+    /// [`broadcast_signed_async`](NearClient::broadcast_signed_async())
+    ///
+    /// ## Arguments
+    ///
+    /// - **finality** - Block [`Finality`], it's used to query a block hash, if it isn't set
+    pub async fn build_signed_transaction(&self, finality: Finality) -> Result<SignedTransaction> {
+        let block_hash = match self.block_hash {
+            Some(block_hash) => block_hash,
+            None => self.info.client().block(finality).await?,
+        };
+
+        Ok(NearClient::sign_transaction_offline(
+            self.info.signer(),
+            self.info.contract().clone(),
+            self.actions.clone(),
+            self.info.signer().reserve_nonce(),
+            block_hash,
+        ))
+    }
+
     /// Set [`Retry`] strategy
     pub const fn retry(mut self, retry: Retry) -> Self {
         self.retry = retry;
         self
     }
 
+    /// Sign a transaction with the given block hash, instead of querying the most recent one.
+    /// If the block hash is expired and [`Retry`] is set, then a transaction will be
+    /// re-signed with the most recent block hash
+    ///
+    /// ## Arguments
+    ///
+    /// - **block_hash** - Block [`CryptoHash`]
+    pub const fn block_hash(mut self, block_hash: CryptoHash) -> Self {
+        self.block_hash = Some(block_hash);
+        self
+    }
+
+    /// Set a delay between retries, that are caused by transient network errors
+    pub const fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = Some(backoff);
+        self
+    }
+
+    /// Set a timeout of the transaction request, that overrides the client timeout
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Validates transaction actions locally, before they will be sent to the network.
+    /// Limits could be queried with [`actions_limits`](NearClient::actions_limits())
+    ///
+    /// ## Arguments
+    ///
+    /// - **limits** - [`ActionsLimits`] of the protocol
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::BatchLimitsExceeded`], if total prepaid gas or number of actions exceeds the limits
+    #[allow(clippy::result_large_err)]
+    pub fn validate(self, limits: &ActionsLimits) -> Result<Self> {
+        limits
+            .validate(&self.actions)
+            .map_err(Error::BatchLimitsExceeded)?;
+        Ok(self)
+    }
+
     const fn info(&self) -> &TransactionInfo {
         &self.info
     }
@@ -735,68 +2915,221 @@ impl<'a> FunctionCall<'a> {
             info,
             actions,
             retry: Retry::NONE,
+            retry_backoff: None,
+            block_hash: None,
+            timeout: None,
+        }
+    }
+}
+
+/// The transaction execution status, that corresponds to the block [`Finality`]
+const fn tx_execution_status(finality: &Finality) -> &'static str {
+    match finality {
+        Finality::None => "EXECUTED_OPTIMISTIC",
+        Finality::DoomSlug => "EXECUTED",
+        Finality::Final => "FINAL",
+    }
+}
+
+/// Repeats the `query` until it returns a result, the interval between queries is doubled after each one.
+/// Returns [`None`], if there is no result in time
+async fn poll_until<T, F, Fut>(poll: Duration, timeout: Duration, mut query: F) -> Option<Result<T>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Option<Result<T>>>,
+{
+    let polling = async {
+        let mut poll = poll.max(MIN_POLL_INTERVAL);
+        loop {
+            if let Some(result) = query().await {
+                return result;
+            }
+
+            Delay::new(poll).await;
+            poll = poll.saturating_mul(2).min(timeout.max(MIN_POLL_INTERVAL));
         }
+    };
+    futures::pin_mut!(polling);
+
+    match future::select(polling, Delay::new(timeout)).await {
+        Either::Left((result, _)) => Some(result),
+        Either::Right(_) => None,
     }
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            transaction_type = %transaction_type,
+            signer_id = %call.info().signer().account(),
+            receiver_id = %call.info().contract(),
+        )
+    )
+)]
 async fn commit_with_retry<'a>(
     call: &FunctionCall<'a>,
     finality: Finality,
     transaction_type: &'static str,
     retry: Retry,
 ) -> Result<Value> {
+    retry.validate()?;
+
     let mut execution_count = 0;
-    let retry_count = retry as usize;
+    let attempts = retry.attempts();
+    let mut block_hash = call.block_hash;
+    let mut nonce = None;
 
     loop {
         execution_count += 1;
 
+        // The block hash and the nonce are pinned, so a transaction that is re-sent after a network error
+        // stays the same and can't be executed twice
+        let transaction_block_hash = match block_hash {
+            Some(block_hash) => block_hash,
+            None => call.info().client().block(finality.clone()).await?,
+        };
+        let transaction_nonce = match nonce.take() {
+            Some(nonce) => nonce,
+            None => call.info().signer().reserve_nonce(),
+        };
         let transaction = BASE64_STANDARD_NO_PAD.encode(
-            serialize_transaction(call.info(), call.actions().to_vec(), finality.clone()).await?,
+            serialize_transaction(
+                call.info(),
+                call.actions().to_vec(),
+                transaction_nonce,
+                Some(transaction_block_hash),
+                finality.clone(),
+            )
+            .await?,
         );
 
         let resp = call
             .info()
             .rpc()
-            .request(transaction_type, Some(json!(vec![transaction])))
+            .request_with_timeout(
+                transaction_type,
+                Some(json!(vec![transaction])),
+                call.timeout,
+            )
             .await
             .map_err(transaction_error);
 
-        if let Err(Error::TxExecution(
-            TxExecutionError::InvalidTxError(InvalidTxError::InvalidNonce { ak_nonce, .. }),
-            ..,
-        )) = resp
-        {
-            if retry_count > 1 && execution_count <= retry_count {
-                call.info().signer().update_nonce(ak_nonce + 1);
-                continue;
+        if execution_count >= attempts {
+            return resp;
+        }
+
+        let mut retry_after = None;
+        let backoff = match resp {
+            Err(Error::TxExecution(TxExecutionError::InvalidTxError(ref err), ..)) => match err {
+                InvalidTxError::InvalidNonce { ak_nonce, .. } => {
+                    call.info().signer().update_nonce(ak_nonce + 1);
+                    None
+                }
+                // The block hash is too old,
+                // so the transaction is signed with the most recent one
+                InvalidTxError::Expired => {
+                    block_hash = None;
+                    None
+                }
+                _ => return resp,
+            },
+            Err(Error::RpcError(ref err)) if err.is_transient() => {
+                block_hash = Some(transaction_block_hash);
+                nonce = Some(transaction_nonce);
+                retry_after = err.retry_after();
+                call.retry_backoff
             }
+            _ => return resp,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(attempt = execution_count, error = ?resp.as_ref().err(), "retrying the transaction");
+
+        // A rate limited request isn't retried earlier, than the provider asks
+        if let Some(delay) = retry
+            .delay(execution_count - 1)
+            .or(backoff)
+            .max(retry_after)
+        {
+            Delay::new(delay).await;
         }
+    }
+}
+
+// Adds a block reference to the query params, like `"block_id": 1` or `"finality": "final"`
+#[allow(clippy::result_large_err)]
+fn query_params(mut params: Value, block_reference: BlockReference) -> Result<Value> {
+    let block_reference =
+        serde_json::to_value(block_reference).map_err(Error::ArgsSerialization)?;
+
+    if let (Value::Object(params), Value::Object(block_reference)) = (&mut params, block_reference)
+    {
+        params.extend(block_reference);
+    }
+
+    Ok(params)
+}
 
-        return resp;
+// The view access key call returns the error message instead of the result, if the key isn't found
+#[allow(clippy::result_large_err)]
+fn access_key_view(resp: Value) -> Result<AccessKeyView> {
+    let view_access_key = serde_json::from_value::<ViewAccessKey>(resp)
+        .map_err(Error::DeserializeAccessKeyViewCall)?;
+
+    match view_access_key.result {
+        ViewAccessKeyResult::Ok(access_key_view) => Ok(access_key_view),
+        ViewAccessKeyResult::Err { error, logs } => {
+            Err(Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError {
+                error,
+                logs,
+            }))
+        }
     }
 }
 
+// The failed view call returns the error message instead of the result
+fn query_error(cause: Value, logs: Vec<String>) -> QueryError {
+    let error = match cause {
+        Value::String(error) => error,
+        cause => cause.to_string(),
+    };
+
+    QueryError { error, logs }
+}
+
 // Try to parse the error that may be located in the node response
 fn transaction_error(err: RpcError) -> Error {
     let RpcError::NearProtocol(near_err) = &err else {
         return Error::RpcError(err);
     };
 
+    match parse_tx_execution_error(near_err) {
+        Some(tx_execution_error) => Error::TxExecution(tx_execution_error, Default::default()),
+        None => Error::RpcError(err),
+    }
+}
+
+/// Extracts a [`TxExecutionError`] from an error of a transaction request.
+/// The node puts it either into the cause info, or into the error data, depending on its version
+pub(crate) fn parse_tx_execution_error(near_err: &NearError) -> Option<TxExecutionError> {
     let (NearErrorVariant::RequestValidation(CauseKind::ParseError(cause))
     | NearErrorVariant::Handler(CauseKind::InvalidTransaction(cause))) = near_err.error()
     else {
-        return Error::RpcError(err);
+        return None;
     };
 
-    serde_json::from_value::<TxExecutionErrorContainer>(cause.to_owned())
+    TxExecutionErrorContainer::try_from(cause.to_owned())
         .or_else(|err| {
-            near_err.data().ok_or(err).and_then(|cause| {
-                serde_json::from_value::<TxExecutionErrorContainer>(cause.to_owned())
-            })
+            near_err
+                .data()
+                .ok_or(err)
+                .and_then(|cause| TxExecutionErrorContainer::try_from(cause.to_owned()))
         })
-        .map(|exec_err| Error::TxExecution(exec_err.tx_execution_error, Default::default()))
-        .unwrap_or(Error::RpcError(err))
+        .map(TxExecutionErrorContainer::into_tx_execution_error)
+        .ok()
 }
 
 #[allow(clippy::result_large_err)]
@@ -804,9 +3137,23 @@ pub(crate) fn proceed_outcome(
     signer: &Signer,
     execution_outcome: FinalExecutionOutcomeView,
 ) -> Result<Output> {
-    signer.update_nonce(execution_outcome.transaction.nonce);
+    // The concurrent transactions could be finished in any order,
+    // so the nonce, that is already reserved by a later one, isn't rolled back
+    signer
+        .nonce
+        .fetch_max(execution_outcome.transaction.nonce, Ordering::AcqRel);
+    into_output(execution_outcome)
+}
+
+#[allow(clippy::result_large_err)]
+fn into_output(execution_outcome: FinalExecutionOutcomeView) -> Result<Output> {
+    let total_gas_burnt = execution_outcome.total_gas_burnt();
+    let total_tokens_burnt = execution_outcome.total_tokens_burnt();
+    let gas_profile = execution_outcome.gas_profile();
     let transaction = execution_outcome.transaction_outcome;
-    let logs = extract_logs(execution_outcome.receipts_outcome);
+    let receipts = execution_outcome.receipts_outcome;
+    // The transaction outcome goes first, then the receipts outcomes
+    let logs = extract_logs(std::iter::once(&transaction).chain(&receipts));
 
     match execution_outcome.status {
         FinalExecutionStatus::Failure(err) => Err(Error::TxExecution(err, Box::new(logs))),
@@ -814,12 +3161,517 @@ pub(crate) fn proceed_outcome(
             transaction,
             logs,
             data,
+            total_gas_burnt,
+            total_tokens_burnt,
+            gas_profile,
+            finished: true,
         }),
         FinalExecutionStatus::NotStarted => Err(Error::TxNotStarted(Box::new(logs))),
         FinalExecutionStatus::Started => Ok(Output {
             transaction,
             logs,
             data: vec![],
+            total_gas_burnt,
+            total_tokens_burnt,
+            gas_profile,
+            finished: false,
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{
+            ActionError, ActionErrorKind, ActionsValidationError, FunctionCallError,
+            MethodResolveError,
+        },
+        rpc::transport::MockTransport,
+    };
+    use futures::StreamExt;
+    use near_primitives_core::hash::hash;
+    use std::str::FromStr;
+
+    fn signer() -> Signer {
+        let secret_key = Ed25519SecretKey::try_from_bytes(&[1; 32]).unwrap();
+        Signer::from_secret(
+            secret_key,
+            AccountId::from_str("alice.test.near").unwrap(),
+            0,
+        )
+    }
+
+    fn near_error(cause: Value) -> RpcError {
+        RpcError::NearProtocol(serde_json::from_value(cause).unwrap())
+    }
+
+    fn invalid_nonce(tx_nonce: Nonce, ak_nonce: Nonce) -> RpcError {
+        near_error(json!({
+            "name": "HANDLER_ERROR",
+            "cause": {
+                "name": "INVALID_TRANSACTION",
+                "info": {
+                    "TxExecutionError": {
+                        "InvalidTxError": {
+                            "InvalidNonce": { "tx_nonce": tx_nonce, "ak_nonce": ak_nonce }
+                        }
+                    }
+                }
+            },
+            "data": null,
+            "message": "Invalid transaction"
+        }))
+    }
+
+    #[test]
+    fn transaction_error_parsing() {
+        assert!(matches!(
+            transaction_error(invalid_nonce(1, 5)),
+            Error::TxExecution(
+                TxExecutionError::InvalidTxError(InvalidTxError::InvalidNonce {
+                    tx_nonce: 1,
+                    ak_nonce: 5
+                }),
+                ..
+            )
+        ));
+
+        let timeout = near_error(json!({
+            "name": "HANDLER_ERROR",
+            "cause": { "name": "TIMEOUT_ERROR" },
+            "data": null,
+            "message": "Timeout"
+        }));
+        assert!(matches!(
+            transaction_error(timeout),
+            Error::RpcError(RpcError::NearProtocol(_))
+        ));
+    }
+
+    #[test]
+    fn parse_tx_execution_error_fixtures() {
+        let parse = |fixture: Value| {
+            parse_tx_execution_error(&serde_json::from_value::<NearError>(fixture).unwrap())
+        };
+
+        let invalid_nonce = parse(json!({
+            "name": "HANDLER_ERROR",
+            "cause": {
+                "name": "INVALID_TRANSACTION",
+                "info": {
+                    "TxExecutionError": {
+                        "InvalidTxError": {
+                            "InvalidNonce": { "ak_nonce": 101, "tx_nonce": 100 }
+                        }
+                    }
+                }
+            },
+            "code": -32000,
+            "message": "Server error",
+            "data": {
+                "TxExecutionError": {
+                    "InvalidTxError": { "InvalidNonce": { "ak_nonce": 101, "tx_nonce": 100 } }
+                }
+            }
+        }));
+        assert_eq!(
+            invalid_nonce,
+            Some(TxExecutionError::InvalidTxError(
+                InvalidTxError::InvalidNonce {
+                    tx_nonce: 100,
+                    ak_nonce: 101
+                }
+            ))
+        );
+        assert!(invalid_nonce.as_ref().unwrap().is_invalid_nonce());
+        assert!(!invalid_nonce.as_ref().unwrap().is_expired());
+
+        let method_not_found = parse(json!({
+            "name": "HANDLER_ERROR",
+            "cause": {
+                "name": "INVALID_TRANSACTION",
+                "info": {
+                    "TxExecutionError": {
+                        "ActionError": {
+                            "index": 0,
+                            "kind": { "FunctionCallError": { "MethodResolveError": "MethodNotFound" } }
+                        }
+                    }
+                }
+            },
+            "code": -32000,
+            "message": "Server error",
+            "data": null
+        }));
+        assert!(matches!(
+            method_not_found,
+            Some(TxExecutionError::ActionError(ActionError {
+                index: Some(0),
+                kind: ActionErrorKind::FunctionCallError(FunctionCallError::MethodResolveError(
+                    MethodResolveError::MethodNotFound
+                )),
+            }))
+        ));
+        assert!(method_not_found.as_ref().unwrap().is_method_not_found());
+        assert_eq!(method_not_found.as_ref().unwrap().is_guest_panic(), None);
+
+        // The older nodes put the error only into the data
+        let execution_error = parse(json!({
+            "name": "REQUEST_VALIDATION_ERROR",
+            "cause": {
+                "name": "PARSE_ERROR",
+                "info": { "error_message": "Smart contract panicked: out of tokens" }
+            },
+            "code": -32000,
+            "message": "Server error",
+            "data": {
+                "TxExecutionError": {
+                    "ActionError": {
+                        "index": 0,
+                        "kind": {
+                            "FunctionCallError": {
+                                "ExecutionError": "Smart contract panicked: out of tokens"
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+        assert!(matches!(
+            execution_error,
+            Some(TxExecutionError::ActionError(ActionError {
+                kind: ActionErrorKind::FunctionCallError(FunctionCallError::ExecutionError(msg)),
+                ..
+            })) if msg == "Smart contract panicked: out of tokens"
+        ));
+        assert_eq!(
+            execution_error.as_ref().unwrap().is_guest_panic(),
+            Some("out of tokens")
+        );
+        assert!(!execution_error.as_ref().unwrap().is_not_enough_balance());
+
+        let timeout = parse(json!({
+            "name": "HANDLER_ERROR",
+            "cause": { "name": "TIMEOUT_ERROR" },
+            "code": -32000,
+            "message": "Server error",
+            "data": null
+        }));
+        assert_eq!(timeout, None);
+    }
+
+    #[test]
+    fn retry_delay_is_clamped() {
+        let retry = Retry::Backoff {
+            max_attempts: 100,
+            base: Duration::from_secs(1),
+            factor: 2.0,
+            jitter: false,
+        };
+
+        assert_eq!(retry.delay(0), Some(Duration::from_secs(1)));
+        assert_eq!(retry.delay(3), Some(Duration::from_secs(8)));
+        assert_eq!(retry.delay(99), Some(MAX_RETRY_DELAY));
+        assert_eq!(retry.delay(usize::MAX), Some(MAX_RETRY_DELAY));
+
+        let retry = Retry::Backoff {
+            max_attempts: usize::MAX,
+            base: Duration::ZERO,
+            factor: 2.0,
+            jitter: true,
+        };
+        assert_eq!(retry.delay(usize::MAX), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_factor_validation() {
+        let backoff = |factor| Retry::Backoff {
+            max_attempts: 3,
+            base: Duration::from_secs(1),
+            factor,
+            jitter: false,
+        };
+
+        assert!(backoff(1.0).validate().is_ok());
+        assert!(Retry::TWICE.validate().is_ok());
+        for factor in [0.5, -2.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                backoff(factor).validate(),
+                Err(Error::InvalidRetryFactor(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_invalid_nonce() {
+        let id = hash(b"transaction");
+        let transport = Arc::new(
+            MockTransport::default()
+                .respond("broadcast_tx_async", Err(invalid_nonce(1, 5)))
+                .respond("broadcast_tx_async", Ok(json!(id))),
+        );
+        let client = NearClient::from_transport(Arc::clone(&transport));
+        let signer = signer();
+        let receiver_id = AccountId::from_str("bob.test.near").unwrap();
+
+        let output = client
+            .send(&signer, &receiver_id, crate::NearToken::from_yocto(1))
+            .block_hash(CryptoHash::default())
+            .retry(Retry::ONCE)
+            .commit_async(Finality::Final)
+            .await
+            .unwrap();
+        assert_eq!(output, id);
+
+        // The transaction is re-signed with a nonce, that is greater than the access key one
+        let nonces = transport
+            .requests()
+            .into_iter()
+            .map(|(_, params)| {
+                let transaction = params.unwrap()[0].as_str().unwrap().to_owned();
+                let bytes = BASE64_STANDARD_NO_PAD.decode(transaction).unwrap();
+                SignedTransaction::try_from_slice(&bytes)
+                    .unwrap()
+                    .transaction
+                    .nonce
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(nonces.len(), 2);
+        assert!(nonces[1] > 5);
+    }
+
+    #[tokio::test]
+    async fn await_transaction_polling() {
+        let unknown_transaction = || {
+            Err(near_error(json!({
+                "name": "HANDLER_ERROR",
+                "cause": {
+                    "name": "UNKNOWN_TRANSACTION",
+                    "info": { "requested_transaction_hash": hash(b"transaction") }
+                },
+                "data": null,
+                "message": "Server error"
+            })))
+        };
+        let transport = Arc::new(
+            MockTransport::default()
+                .respond("EXPERIMENTAL_tx_status", unknown_transaction())
+                .respond("EXPERIMENTAL_tx_status", unknown_transaction())
+                .respond(
+                    "EXPERIMENTAL_tx_status",
+                    Err(RpcError::Http {
+                        method: "EXPERIMENTAL_tx_status".to_owned(),
+                        status: 400,
+                        body: "Bad request".to_owned(),
+                    }),
+                ),
+        );
+        let client = NearClient::from_transport(Arc::clone(&transport));
+
+        // The zero interval is raised to the minimal one
+        let started = std::time::Instant::now();
+        let result = client
+            .await_transaction(
+                &hash(b"transaction"),
+                &signer(),
+                Duration::ZERO,
+                Duration::from_secs(10),
+            )
+            .await;
+        assert!(started.elapsed() >= MIN_POLL_INTERVAL * 3);
+
+        // Only an unknown transaction is polled again, other errors are returned
+        assert!(matches!(
+            result,
+            Err(Error::ViewTransaction(RpcError::Http { status: 400, .. }))
+        ));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn view_timeout() {
+        // Never responds
+        struct PendingTransport;
+
+        impl crate::transport::Transport for PendingTransport {
+            fn request<'a>(
+                &'a self,
+                _: &'a str,
+                _: Option<Value>,
+            ) -> futures::future::BoxFuture<'a, std::result::Result<Value, RpcError>> {
+                Box::pin(future::pending())
+            }
+        }
+
+        let client = NearClient::from_transport(PendingTransport);
+        let account_id = AccountId::from_str("alice.test.near").unwrap();
+
+        assert!(matches!(
+            client
+                .view_account(&account_id)
+                .timeout(Duration::from_millis(50))
+                .await,
+            Err(Error::RequestTimeout(..))
+        ));
+    }
+
+    #[test]
+    fn batch_limits() {
+        let client = NearClient::from_transport(MockTransport::default());
+        let signer = signer();
+        let receiver_id = AccountId::from_str("bob.test.near").unwrap();
+        let limits = ActionsLimits::default();
+
+        let oversized = (0..=limits.max_actions_per_receipt)
+            .fold(client.batch(&signer, &receiver_id), |batch, _| {
+                batch.transfer(crate::NearToken::from_yocto(1))
+            });
+        assert!(matches!(
+            oversized.build(),
+            Err(Error::BatchLimitsExceeded(
+                ActionsValidationError::TotalNumberOfActionsExceeded { .. }
+            ))
+        ));
+
+        let overpaid = client
+            .batch(&signer, &receiver_id)
+            .function_call("first", None, limits.max_total_prepaid_gas, 0)
+            .function_call("second", None, 1, 0);
+        assert!(matches!(
+            overpaid.build(),
+            Err(Error::BatchLimitsExceeded(
+                ActionsValidationError::TotalPrepaidGasExceeded { .. }
+            ))
+        ));
+
+        // the limits of another network
+        assert!(client
+            .batch(&signer, &receiver_id)
+            .function_call("first", None, limits.max_total_prepaid_gas, 0)
+            .function_call("second", None, 1, 0)
+            .limits(ActionsLimits {
+                max_total_prepaid_gas: limits.max_total_prepaid_gas + 1,
+                ..limits
+            })
+            .build()
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn status_of_transaction_finality() {
+        let id = hash(b"transaction");
+        let sender_id = AccountId::from_str("alice.test.near").unwrap();
+        // Each finality is responded only, if the request waits for the matching status
+        let transport = ["EXECUTED_OPTIMISTIC", "EXECUTED", "FINAL"]
+            .into_iter()
+            .fold(MockTransport::default(), |transport, wait_until| {
+                transport.respond_to(
+                    "tx",
+                    json!({
+                        "tx_hash": id,
+                        "sender_account_id": sender_id,
+                        "wait_until": wait_until,
+                    }),
+                    Err(RpcError::MissingBatchResponse {
+                        method: "tx".to_owned(),
+                    }),
+                )
+            });
+        let transport = Arc::new(transport);
+        let client = NearClient::from_transport(Arc::clone(&transport));
+
+        for finality in [Finality::None, Finality::DoomSlug, Finality::Final] {
+            let id = TransactionOrReceiptId::Transaction {
+                transaction_hash: id,
+                sender_id: sender_id.clone(),
+            };
+            assert!(matches!(
+                client.status_of(id, finality).await,
+                Err(Error::ViewTransaction(
+                    RpcError::MissingBatchResponse { .. }
+                ))
+            ));
+        }
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn view_paginated_args() {
+        let page =
+            |items: &[u64]| Ok(json!({ "result": serde_json::to_vec(items).unwrap(), "logs": [] }));
+        let transport = Arc::new(
+            MockTransport::default()
+                .respond("query", page(&[1, 2]))
+                .respond("query", page(&[3]))
+                .respond("query", page(&[4, 5]))
+                .respond("query", page(&[])),
+        );
+        let client = NearClient::from_transport(Arc::clone(&transport));
+        let contract_id = AccountId::from_str("contract.test.near").unwrap();
+
+        let items = client
+            .view_paginated::<u64>(&contract_id, "nft_tokens", 2, Finality::Final)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            items.into_iter().collect::<Result<Vec<_>>>().unwrap(),
+            [1, 2, 3]
+        );
+
+        let items = client
+            .view_paginated_with::<u64>(
+                &contract_id,
+                "get_items",
+                Pagination::new(2)
+                    .from_index_arg("offset")
+                    .limit_arg("count")
+                    .numeric_index(),
+                Finality::Final,
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            items.into_iter().collect::<Result<Vec<_>>>().unwrap(),
+            [4, 5]
+        );
+
+        let args = transport
+            .requests()
+            .into_iter()
+            .map(|(_, params)| {
+                let args = params.unwrap()["args_base64"].as_str().unwrap().to_owned();
+                serde_json::from_slice::<Value>(&BASE64_STANDARD_NO_PAD.decode(args).unwrap())
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            args,
+            [
+                json!({ "from_index": "0", "limit": 2 }),
+                json!({ "from_index": "2", "limit": 2 }),
+                json!({ "offset": 0, "count": 2 }),
+                json!({ "offset": 2, "count": 2 }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn canned_responses_by_params() {
+        let client = NearClient::from_transport(
+            MockTransport::default()
+                .respond_to(
+                    "gas_price",
+                    json!([null]),
+                    Ok(json!({ "gas_price": "200" })),
+                )
+                .respond_to("gas_price", json!([1]), Ok(json!({ "gas_price": "100" }))),
+        );
+
+        assert_eq!(
+            client.gas_price(Some(BlockId::Height(1))).await.unwrap(),
+            100
+        );
+        assert_eq!(client.gas_price(None).await.unwrap(), 200);
+    }
+}
@@ -1,12 +1,26 @@
 use crate::{
     client::{NearClient, Signer},
-    near_primitives_light::views::{AccessKeyListView, AccessKeyView},
+    crypto::prelude::NearPublicKey,
+    near_primitives_light::{
+        errors::ActionsValidationError,
+        transaction::Action,
+        types::StateChanges,
+        views::{AccessKeyListView, AccessKeyView, AccountView, StatusResponse},
+    },
     rpc::client::RpcClient,
+    Error, Result,
+};
+use borsh::BorshDeserialize;
+use near_primitives_core::{
+    account::id::AccountId,
+    hash::CryptoHash,
+    serialize::dec_format,
+    types::{Balance, BlockHeight, Gas},
 };
-use near_primitives_core::{account::id::AccountId, hash::CryptoHash, types::BlockHeight};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::{base64::Base64, serde_as};
+use serde_with::{base64::Base64, hex::Hex, serde_as};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum CallResult {
@@ -73,11 +87,187 @@ pub struct StateItem {
     pub value: Vec<u8>,
 }
 
+/// The same as [`StateItem`], but key and value are
+/// represented in a hex format
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateItemHex {
+    /// Key in a binary format
+    #[serde_as(as = "Hex")]
+    pub key: Vec<u8>,
+    /// Value in a binary format
+    #[serde_as(as = "Hex")]
+    pub value: Vec<u8>,
+}
+
+impl From<StateItem> for StateItemHex {
+    fn from(StateItem { key, value }: StateItem) -> Self {
+        Self { key, value }
+    }
+}
+
+impl From<StateItemHex> for StateItem {
+    fn from(StateItemHex { key, value }: StateItemHex) -> Self {
+        Self { key, value }
+    }
+}
+
 /// View contract state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewStateResult {
     /// Records in a contract storage
     pub values: Vec<StateItem>,
+    /// Hash of the block, at which the state was read
+    pub block_hash: CryptoHash,
+    /// Height of the block, at which the state was read
+    pub block_height: BlockHeight,
+}
+
+impl ViewStateResult {
+    /// Converts the records into a map of the decoded keys and values, sorted by a key
+    pub fn into_map(self) -> BTreeMap<Vec<u8>, Vec<u8>> {
+        self.values
+            .into_iter()
+            .map(|StateItem { key, value }| (key, value))
+            .collect()
+    }
+
+    /// Deserializes a [borsh](https://docs.rs/borsh) value, that is stored at the `key`
+    ///
+    /// Return
+    ///
+    /// - ```Ok(Some(value))```, if the record exists
+    /// - ```Ok(None)```, if there is no record with the `key`
+    /// - ```Err```, if the value can't be deserialized into `T`
+    #[allow(clippy::result_large_err)]
+    pub fn get_borsh<T: BorshDeserialize>(&self, key: &[u8]) -> Result<Option<T>> {
+        self.values
+            .iter()
+            .find(|item| item.key == key)
+            .map(|item| T::try_from_slice(&item.value).map_err(Error::DeserializeStateValue))
+            .transpose()
+    }
+}
+
+/// View account with the block, at which it was read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewAccountResult {
+    /// Account information
+    #[serde(flatten)]
+    pub account: AccountView,
+    /// Hash of the block, at which the account was read
+    pub block_hash: CryptoHash,
+    /// Height of the block, at which the account was read
+    pub block_height: BlockHeight,
+}
+
+/// State changes of the block
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct StateChangesView {
+    pub block_hash: CryptoHash,
+    pub changes: StateChanges,
+}
+
+/// Protocol limits, that are applied to the actions of a single transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionsLimits {
+    /// Max amount of gas, that could be prepaid for all actions
+    pub max_total_prepaid_gas: Gas,
+    /// Max number of actions in a single transaction
+    pub max_actions_per_receipt: u64,
+}
+
+impl Default for ActionsLimits {
+    /// Limits of the Near mainnet
+    fn default() -> Self {
+        Self {
+            max_total_prepaid_gas: 300_000_000_000_000,
+            max_actions_per_receipt: 100,
+        }
+    }
+}
+
+impl ActionsLimits {
+    /// Validates actions against the limits
+    ///
+    /// ## Arguments
+    ///
+    /// - **actions** - Actions of a transaction
+    ///
+    /// ## Errors
+    ///
+    /// - [`ActionsValidationError::TotalNumberOfActionsExceeded`], if there are too many actions
+    /// - [`ActionsValidationError::TotalPrepaidGasExceeded`], if prepaid gas exceeds the limit
+    /// - [`ActionsValidationError::IntegerOverflow`], if prepaid gas doesn't fit into [`Gas`]
+    pub fn validate(&self, actions: &[Action]) -> Result<(), ActionsValidationError> {
+        let total_number_of_actions = actions.len() as u64;
+        if total_number_of_actions > self.max_actions_per_receipt {
+            return Err(ActionsValidationError::TotalNumberOfActionsExceeded {
+                total_number_of_actions,
+                limit: self.max_actions_per_receipt,
+            });
+        }
+
+        let total_prepaid_gas = actions
+            .iter()
+            .try_fold(0, |total: Gas, action| {
+                total.checked_add(action.get_prepaid_gas())
+            })
+            .ok_or(ActionsValidationError::IntegerOverflow)?;
+        if total_prepaid_gas > self.max_total_prepaid_gas {
+            return Err(ActionsValidationError::TotalPrepaidGasExceeded {
+                total_prepaid_gas,
+                limit: self.max_total_prepaid_gas,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ProtocolConfigView {
+    pub runtime_config: RuntimeConfigView,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RuntimeConfigView {
+    pub wasm_config: WasmConfigView,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct WasmConfigView {
+    pub limit_config: ActionsLimits,
+}
+
+/// Credentials in the near-cli format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Credentials {
+    pub account_id: AccountId,
+    pub public_key: NearPublicKey,
+    pub private_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GasPriceView {
+    #[serde(with = "dec_format")]
+    pub gas_price: Balance,
+}
+
+/// Fields of the [`StatusResponse`], that don't change over time
+#[derive(Debug, Clone)]
+pub(crate) struct StaticStatus {
+    pub chain_id: String,
+    pub protocol_version: u32,
+}
+
+impl From<&StatusResponse> for StaticStatus {
+    fn from(status: &StatusResponse) -> Self {
+        Self {
+            chain_id: status.chain_id.clone(),
+            protocol_version: status.protocol_version,
+        }
+    }
 }
 
 pub(crate) struct TransactionInfo<'a> {
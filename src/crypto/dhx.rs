@@ -16,6 +16,7 @@ use std::{
 };
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// The public key size for Diffie-Hellman
 pub const PUBLIC_KEY_LENGTH: usize = 32_usize;
@@ -147,6 +148,14 @@ impl SecretKey {
     }
 }
 
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
 impl From<Ed25519PublicKey> for PublicKey {
     fn from(key: Ed25519PublicKey) -> Self {
         PublicKey(key.0.to_montgomery())
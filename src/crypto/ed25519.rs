@@ -5,6 +5,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use ed25519_dalek::{SecretKey, Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use itertools::Itertools;
+use near_primitives_core::types::AccountId;
+use rand::{rngs::OsRng, CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
@@ -12,6 +14,11 @@ use std::{
     io::{Error as IoError, ErrorKind},
     str::FromStr,
 };
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
 
 use super::{split_encoded_str, Error, Key, Result, ED25519};
 
@@ -20,6 +27,11 @@ pub use ed25519_dalek::{
     SECRET_KEY_LENGTH as ED25519_SECRET_KEY_LENGTH, SIGNATURE_LENGTH as ED25519_SIGNATURE_LENGTH,
 };
 
+/// Registered [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md) coin type of Near
+const NEAR_COIN_TYPE: u32 = 397;
+/// Offset of the hardened index in a derivation path
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
 /// The public key wrapper around ed25519-dalek public key
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Ed25519PublicKey(pub(super) VerifyingKey);
@@ -32,11 +44,33 @@ impl Ed25519PublicKey {
             .map_err(|_| Error::Verification(signature.string()))
     }
 
+    /// Verifies the signature of the data in a strict mode.
+    /// Rejects the non-canonical (malleable) signatures and weak public keys,
+    /// should be used for the security sensitive verification
+    pub fn verify_strict(&self, data: &[u8], signature: &Ed25519Signature) -> Result<()> {
+        self.0
+            .verify_strict(data, &signature.0)
+            .map_err(|_| Error::Verification(signature.string()))
+    }
+
     /// Returns a key in the raw bytes
     #[inline]
     pub fn as_bytes(&self) -> &[u8; ED25519_PUBLIC_KEY_LENGTH] {
         self.0.as_bytes()
     }
+
+    /// Returns an implicit account id, that is a lowercase hex representation of the key.
+    /// Could be checked with [`AccountId::is_implicit`]
+    pub fn implicit_account_id(&self) -> AccountId {
+        let hex = self
+            .as_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        // 64 lowercase hex characters are always a valid account id
+        hex.parse()
+            .expect("Implicit account id should be a valid account id")
+    }
 }
 
 impl Key<ED25519_PUBLIC_KEY_LENGTH> for Ed25519PublicKey {
@@ -111,6 +145,48 @@ impl Ed25519SecretKey {
         Ed25519Signature(SigningKey::from(self.0).sign(data))
     }
 
+    /// Derives the [`Ed25519PublicKey`] from a private key
+    pub fn public_key(&self) -> Ed25519PublicKey {
+        Ed25519PublicKey::from(self)
+    }
+
+    /// Generates a new random key with an operating system CSPRNG.
+    /// Suitable for the production keys creation
+    pub fn generate() -> Self {
+        Self::generate_from_rng(&mut OsRng)
+    }
+
+    /// Generates a new key with the given CSPRNG,
+    /// a seeded generator could be used for the deterministic tests
+    pub fn generate_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut secret_key = SecretKey::default();
+        rng.fill_bytes(&mut secret_key);
+        Self(secret_key)
+    }
+
+    /// Derives a [`Ed25519SecretKey`] from a [BIP-39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki)
+    /// seed phrase, the same way as the Near wallets do.
+    /// The key is derived with [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+    /// by the `m/44'/397'/{account_index}'` path
+    ///
+    /// ## Arguments
+    ///
+    /// - **phrase** - Seed phrase, usually it consists of 12 words
+    /// - **password** - Optional seed phrase password
+    /// - **account_index** - Index of the key, the default Near wallet key has the index `0`
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::InvalidMnemonic`], if the phrase has a wrong word, length or checksum
+    pub fn from_mnemonic(phrase: &str, password: Option<&str>, account_index: u32) -> Result<Self> {
+        let mnemonic = Mnemonic::parse_normalized(phrase)
+            .map_err(|err| Error::InvalidMnemonic(err.to_string()))?;
+        let mut seed = mnemonic.to_seed_normalized(password.unwrap_or_default());
+        let secret_key = derive_slip10(&seed, &[44, NEAR_COIN_TYPE, account_index]);
+        seed.zeroize();
+        Ok(Self(secret_key))
+    }
+
     /// Get a [`Ed25519SecretKey`] from a [`str`]
     pub fn from_expanded(key: &str) -> Result<Self> {
         let (key_type, bs58_encoded) = split_encoded_str(key)?;
@@ -140,6 +216,41 @@ impl Ed25519SecretKey {
     }
 }
 
+/// Derives a key by the hardened path from a seed,
+/// as it's specified in [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+fn derive_slip10(seed: &[u8], path: &[u32]) -> SecretKey {
+    let hmac = |key: &[u8], data: &[&[u8]]| {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts a key of any size");
+        data.iter().for_each(|it| mac.update(it));
+        let bytes = mac.finalize().into_bytes();
+        let (mut secret_key, mut chain_code) = (SecretKey::default(), SecretKey::default());
+        secret_key.copy_from_slice(&bytes[..ED25519_SECRET_KEY_LENGTH]);
+        chain_code.copy_from_slice(&bytes[ED25519_SECRET_KEY_LENGTH..]);
+        (secret_key, chain_code)
+    };
+
+    let (mut secret_key, mut chain_code) = hmac(b"ed25519 seed", &[seed]);
+    for index in path {
+        // ed25519 supports only the hardened derivation
+        let index = (index | HARDENED_OFFSET).to_be_bytes();
+        let (next_secret_key, next_chain_code) = hmac(&chain_code, &[&[0], &secret_key, &index]);
+        secret_key.zeroize();
+        chain_code.zeroize();
+        (secret_key, chain_code) = (next_secret_key, next_chain_code);
+    }
+
+    chain_code.zeroize();
+    secret_key
+}
+
+impl Drop for Ed25519SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Ed25519SecretKey {}
+
 impl Key<ED25519_SECRET_KEY_LENGTH> for Ed25519SecretKey {
     const KEY_TYPE: &'static str = ED25519;
 
@@ -239,6 +350,9 @@ pub struct Keypair {
     secret_key: Ed25519SecretKey,
 }
 
+// The secret key is zeroed by its own drop
+impl ZeroizeOnDrop for Keypair {}
+
 impl Keypair {
     /// Creates a new keypair from the [`Ed25519SecretKey`]
     pub fn new(secret_key: Ed25519SecretKey) -> Self {
@@ -249,6 +363,18 @@ impl Keypair {
         }
     }
 
+    /// Generates a new random keypair with an operating system CSPRNG.
+    /// Suitable for the production keys creation
+    pub fn generate() -> Self {
+        Self::new(Ed25519SecretKey::generate())
+    }
+
+    /// Generates a new keypair with the given CSPRNG,
+    /// a seeded generator could be used for the deterministic tests
+    pub fn generate_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::new(Ed25519SecretKey::generate_from_rng(rng))
+    }
+
     /// Creates a new keypair from the string representation
     ///
     /// **Example**: ```ed25519:5nEtNZTBUPJUwB7v9tfCgm1xfp1E7wXcZdWDpz1JwKckqG5pqstumaqRHJjtfFZMtik4TpgCVmmpvpxjEcq3CTLx```
@@ -280,6 +406,12 @@ impl Keypair {
         self.public_key.verify(data, signature)
     }
 
+    /// Verify the signed data in a strict mode,
+    /// see [verify_strict](Ed25519PublicKey::verify_strict())
+    pub fn verify_strict(&self, data: &[u8], signature: &Ed25519Signature) -> Result<()> {
+        self.public_key.verify_strict(data, signature)
+    }
+
     /// Returns the public key from the keypair
     pub fn public_key(&self) -> &Ed25519PublicKey {
         &self.public_key
@@ -342,3 +474,33 @@ impl FromStr for Keypair {
 serde_impl!(Ed25519PublicKey);
 serde_impl!(Ed25519SecretKey);
 serde_impl!(Ed25519Signature);
+
+#[cfg(test)]
+mod tests {
+    use super::{derive_slip10, SecretKey};
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Test vector 1 for ed25519 from the SLIP-0010
+    #[test]
+    fn slip10_test_vector() {
+        let seed = from_hex("000102030405060708090a0b0c0d0e0f");
+
+        let master: SecretKey = derive_slip10(&seed, &[]);
+        assert_eq!(
+            master.to_vec(),
+            from_hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7")
+        );
+
+        let child: SecretKey = derive_slip10(&seed, &[0]);
+        assert_eq!(
+            child.to_vec(),
+            from_hex("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3")
+        );
+    }
+}
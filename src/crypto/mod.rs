@@ -1,7 +1,7 @@
 //! Fast and efficient Rust implementation of ed25519 key generation, signing, and verification
 //! Used a [Dalek](https://github.com/dalek-cryptography/ed25519-dalek) cryptography
 //! By default near is using two kinds of keys. ed25519 and secp256k1.
-//! For simplicity secp256k1 is removed and used ed25519 only.
+//! The secp256k1 keys are implemented with a [k256](https://github.com/RustCrypto/elliptic-curves)
 
 #[macro_use]
 mod serde_impl {
@@ -41,6 +41,9 @@ mod serde_impl {
 
 pub mod dhx;
 pub mod ed25519;
+pub mod public_key;
+pub mod secp256k1;
+pub mod signature;
 /// Crypto prelude
 pub mod prelude {
     pub use super::{
@@ -49,6 +52,12 @@ pub mod prelude {
             Ed25519PublicKey, Ed25519SecretKey, Ed25519Signature, Keypair,
             ED25519_PUBLIC_KEY_LENGTH, ED25519_SECRET_KEY_LENGTH, ED25519_SIGNATURE_LENGTH,
         },
+        public_key::NearPublicKey,
+        secp256k1::{
+            Secp256k1PublicKey, Secp256k1SecretKey, Secp256k1Signature,
+            SECP256K1_PUBLIC_KEY_LENGTH, SECP256K1_SECRET_KEY_LENGTH, SECP256K1_SIGNATURE_LENGTH,
+        },
+        signature::NearSignature,
         Error, Key,
     };
 }
@@ -59,12 +68,13 @@ type Result<T> = std::result::Result<T, Error>;
 
 pub(crate) const ED25519: &str = "ed25519";
 pub(crate) const X25519: &str = "x25519";
+pub(crate) const SECP256K1: &str = "secp256k1";
 
 /// ## Key
-/// **KEY_LENGTH** - It's a key size for ed25519 or x25519
+/// **KEY_LENGTH** - It's a key size for ed25519, x25519 or secp256k1
 pub trait Key<const KEY_LENGTH: usize>: Sized {
     /// **KEY_TYPE** - Key type, for internal usage to reduce a boilerplate code.
-    /// It's a prefix for a key string serialization. Possible values are ["ed25519", "x25519"]
+    /// It's a prefix for a key string serialization. Possible values are ["ed25519", "x25519", "secp256k1"]
     const KEY_TYPE: &'static str;
 
     /// Parse an encoded string to the corresponding [`Key`]
@@ -86,7 +96,7 @@ pub trait Key<const KEY_LENGTH: usize>: Sized {
 
     /// Return a string representation of a [`Key`]
     /// The string is split with a delimiter ":"
-    /// The first part is a `X25519` or `ED25519` prefix
+    /// The first part is a `X25519`, `ED25519` or `SECP256K1` prefix
     /// The second part is a bs58 encoded key
     fn string(&self) -> String {
         format!(
@@ -130,7 +140,7 @@ pub enum Error {
         cause: String,
     },
     /// Used an unknown key prefix
-    #[error("The key format \"{0}\" seems different from ed25519 or x25519 format")]
+    #[error("The key format \"{0}\" seems different from ed25519, x25519 or secp256k1 format")]
     UnknownKeyType(String),
     /// Key type error
     #[error(
@@ -145,6 +155,9 @@ pub enum Error {
     /// Signature verification Error
     #[error("Signature \"{0}\" verification failed")]
     Verification(String),
+    /// The seed phrase is malformed, the phrase itself isn't included because it's a secret
+    #[error("The seed phrase is invalid, because of: {0}")]
+    InvalidMnemonic(String),
 }
 
 impl Error {
@@ -174,9 +187,11 @@ impl Error {
 /// Split encoded [`str`] to key prefix and bs58 encoded string
 fn split_encoded_str(encoded: &str) -> Result<(&str, &str)> {
     match encoded.split(':').next_tuple() {
-        Some((key_type @ ED25519, bs58_encoded) | (key_type @ X25519, bs58_encoded)) => {
-            Ok((key_type, bs58_encoded))
-        }
+        Some(
+            (key_type @ ED25519, bs58_encoded)
+            | (key_type @ X25519, bs58_encoded)
+            | (key_type @ SECP256K1, bs58_encoded),
+        ) => Ok((key_type, bs58_encoded)),
         _ => Err(Error::UnknownKeyType(encoded.to_owned())),
     }
 }
@@ -184,7 +199,7 @@ fn split_encoded_str(encoded: &str) -> Result<(&str, &str)> {
 #[cfg(test)]
 mod tests {
 
-    use super::{split_encoded_str, Error, ED25519, X25519};
+    use super::{split_encoded_str, Error, ED25519, SECP256K1, X25519};
 
     #[test]
     fn split_encoded() {
@@ -195,6 +210,9 @@ mod tests {
         assert!(matches!(
                 split_encoded_str(&format!("x25519:{bs58_str}")),
                 Ok((key_type, s)) if key_type == X25519 && s == bs58_str));
+        assert!(matches!(
+                split_encoded_str(&format!("secp256k1:{bs58_str}")),
+                Ok((key_type, s)) if key_type == SECP256K1 && s == bs58_str));
         assert!(matches!(
             split_encoded_str(&bs58_str),
             Err(Error::UnknownKeyType(..))
@@ -0,0 +1,174 @@
+//! ### Public key of an access key
+//! ---
+//! Near access keys could be either ed25519 or secp256k1 keys,
+//! so the key is stored with a key type, the same way as the Near protocol does
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::{
+    fmt::Display,
+    io::{Error as IoError, ErrorKind, Read},
+    str::FromStr,
+};
+
+use super::{
+    ed25519::{Ed25519PublicKey, ED25519_PUBLIC_KEY_LENGTH},
+    secp256k1::{Secp256k1PublicKey, SECP256K1_PUBLIC_KEY_LENGTH},
+    split_encoded_str, Error, Key, Result, ED25519, SECP256K1,
+};
+
+/// The key type discriminant of ed25519, that is used by the Near protocol
+const ED25519_KEY_TYPE: u8 = 0;
+/// The key type discriminant of secp256k1, that is used by the Near protocol
+const SECP256K1_KEY_TYPE: u8 = 1;
+
+/// The public key of an access key, it could be either ed25519 or secp256k1 key.
+///
+/// The string representation is prefixed with a key type, like `ed25519:...` or `secp256k1:...`,
+/// the [Borsh](https://borsh.io/) representation is prefixed with a key type byte
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NearPublicKey {
+    /// ed25519 public key
+    Ed25519(Ed25519PublicKey),
+    /// secp256k1 public key
+    Secp256k1(Secp256k1PublicKey),
+}
+
+impl NearPublicKey {
+    /// Returns an [`Ed25519PublicKey`], if it's an ed25519 key
+    pub fn as_ed25519(&self) -> Option<&Ed25519PublicKey> {
+        match self {
+            Self::Ed25519(key) => Some(key),
+            Self::Secp256k1(_) => None,
+        }
+    }
+
+    /// Returns a [`Secp256k1PublicKey`], if it's a secp256k1 key
+    pub fn as_secp256k1(&self) -> Option<&Secp256k1PublicKey> {
+        match self {
+            Self::Ed25519(_) => None,
+            Self::Secp256k1(key) => Some(key),
+        }
+    }
+
+    /// Returns a key type, that is a prefix of the string representation
+    pub fn key_type(&self) -> &'static str {
+        match self {
+            Self::Ed25519(_) => ED25519,
+            Self::Secp256k1(_) => SECP256K1,
+        }
+    }
+}
+
+impl From<Ed25519PublicKey> for NearPublicKey {
+    fn from(key: Ed25519PublicKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+impl From<&Ed25519PublicKey> for NearPublicKey {
+    fn from(key: &Ed25519PublicKey) -> Self {
+        Self::Ed25519(*key)
+    }
+}
+
+impl From<Secp256k1PublicKey> for NearPublicKey {
+    fn from(key: Secp256k1PublicKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl From<&Secp256k1PublicKey> for NearPublicKey {
+    fn from(key: &Secp256k1PublicKey) -> Self {
+        Self::Secp256k1(*key)
+    }
+}
+
+impl From<&NearPublicKey> for NearPublicKey {
+    fn from(key: &NearPublicKey) -> Self {
+        *key
+    }
+}
+
+impl PartialEq<Ed25519PublicKey> for NearPublicKey {
+    fn eq(&self, other: &Ed25519PublicKey) -> bool {
+        self.as_ed25519() == Some(other)
+    }
+}
+
+impl PartialEq<Secp256k1PublicKey> for NearPublicKey {
+    fn eq(&self, other: &Secp256k1PublicKey) -> bool {
+        self.as_secp256k1() == Some(other)
+    }
+}
+
+impl Display for NearPublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ed25519(key) => write!(f, "{}", key.string()),
+            Self::Secp256k1(key) => write!(f, "{}", key.string()),
+        }
+    }
+}
+
+impl FromStr for NearPublicKey {
+    type Err = Error;
+
+    fn from_str(key: &str) -> Result<Self> {
+        match split_encoded_str(key)? {
+            (ED25519, ..) => Ed25519PublicKey::from_string(key).map(Self::Ed25519),
+            (SECP256K1, ..) => Secp256k1PublicKey::from_string(key).map(Self::Secp256k1),
+            _ => Err(Error::UnknownKeyType(key.to_owned())),
+        }
+    }
+}
+
+impl BorshSerialize for NearPublicKey {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Both of the keys are serialized with a key type byte
+        match self {
+            Self::Ed25519(key) => BorshSerialize::serialize(key, writer),
+            Self::Secp256k1(key) => BorshSerialize::serialize(key, writer),
+        }
+    }
+}
+
+impl BorshDeserialize for NearPublicKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The exact amount of bytes is read, so the key could be followed by other fields
+        let key = match u8::deserialize_reader(reader)? {
+            ED25519_KEY_TYPE => {
+                let mut buf = [0; ED25519_PUBLIC_KEY_LENGTH];
+                reader.read_exact(&mut buf)?;
+                Ed25519PublicKey::try_from_bytes(&buf).map(Self::Ed25519)
+            }
+            SECP256K1_KEY_TYPE => {
+                let mut buf = [0; SECP256K1_PUBLIC_KEY_LENGTH];
+                reader.read_exact(&mut buf)?;
+                Secp256k1PublicKey::try_from_bytes(&buf).map(Self::Secp256k1)
+            }
+            key_type => Err(Error::UnknownKeyType(key_type.to_string())),
+        };
+
+        key.map_err(|err| IoError::new(ErrorKind::InvalidData, err))
+    }
+}
+
+impl serde::Serialize for NearPublicKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NearPublicKey {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        NearPublicKey::from_str(&s)
+            .map_err(|err| serde::de::Error::custom(format!("Deserialization failed: `{}`", err)))
+    }
+}
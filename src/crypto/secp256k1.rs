@@ -0,0 +1,219 @@
+//! ### secp256k1 elliptic curve signing algorithm
+//! ---
+//! Used [RustCrypto](https://github.com/RustCrypto/elliptic-curves) k256 implementation,
+//! and implemented [`Borsh`](https://borsh.io/) serialization for them
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use k256::ecdsa::{
+    signature::hazmat::PrehashVerifier, RecoveryId, Signature, SigningKey, VerifyingKey,
+};
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    io::{Error as IoError, ErrorKind, Read},
+};
+
+use super::{Error, Key, Result, SECP256K1};
+
+/// The length of a public key in bytes, it's an uncompressed point without a prefix
+pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = 64;
+/// The length of a secret key in bytes
+pub const SECP256K1_SECRET_KEY_LENGTH: usize = 32;
+/// The length of a signature in bytes, it's a signature with a recovery id at the end
+pub const SECP256K1_SIGNATURE_LENGTH: usize = 65;
+
+/// The key type discriminant, that is used by the Near protocol
+const SECP256K1_KEY_TYPE: u8 = 1;
+/// The prefix of an uncompressed [SEC1](https://www.secg.org/sec1-v2.pdf) point
+const UNCOMPRESSED_POINT_TAG: u8 = 0x04;
+
+/// The public key wrapper around k256 verifying key
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Secp256k1PublicKey(VerifyingKey);
+
+impl Secp256k1PublicKey {
+    /// Verifies the signature of the data.
+    /// The data should be a hash of the message, like a transaction hash
+    pub fn verify(&self, data: &[u8], signature: &Secp256k1Signature) -> Result<()> {
+        let sig = Signature::from_slice(&signature.0[..SECP256K1_SIGNATURE_LENGTH - 1])
+            .map_err(|_| Error::Verification(signature.string()))?;
+        self.0
+            .verify_prehash(data, &sig)
+            .map_err(|_| Error::Verification(signature.string()))
+    }
+}
+
+impl Key<SECP256K1_PUBLIC_KEY_LENGTH> for Secp256k1PublicKey {
+    const KEY_TYPE: &'static str = SECP256K1;
+
+    fn to_bytes(&self) -> [u8; SECP256K1_PUBLIC_KEY_LENGTH] {
+        let point = self.0.to_encoded_point(false);
+        let mut bytes = [0; SECP256K1_PUBLIC_KEY_LENGTH];
+        // Skip the uncompressed point tag, the Near protocol doesn't use it
+        bytes.copy_from_slice(&point.as_bytes()[1..]);
+        bytes
+    }
+
+    fn try_from_bytes(buf: &[u8]) -> Result<Self> {
+        if buf.len() != SECP256K1_PUBLIC_KEY_LENGTH {
+            return Err(Error::from_bytes::<Secp256k1PublicKey>(
+                buf,
+                format!("Public key length doesn't equal to {SECP256K1_PUBLIC_KEY_LENGTH}"),
+            ));
+        }
+
+        let sec1_bytes = std::iter::once(UNCOMPRESSED_POINT_TAG)
+            .chain(buf.iter().copied())
+            .collect::<Vec<_>>();
+        VerifyingKey::from_sec1_bytes(&sec1_bytes)
+            .map(Self)
+            .map_err(|err| Error::from_bytes::<Secp256k1PublicKey>(buf, err.to_string()))
+    }
+}
+
+impl BorshDeserialize for Secp256k1PublicKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The exact amount of bytes is read, so the key could be followed by other fields
+        read_key_type(reader)?;
+        let mut buf = [0; SECP256K1_PUBLIC_KEY_LENGTH];
+        reader.read_exact(&mut buf)?;
+        Secp256k1PublicKey::try_from_bytes(&buf)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))
+    }
+}
+
+impl BorshSerialize for Secp256k1PublicKey {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&SECP256K1_KEY_TYPE, writer)?;
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+impl From<&Secp256k1SecretKey> for Secp256k1PublicKey {
+    fn from(sk: &Secp256k1SecretKey) -> Self {
+        Self(*sk.0.verifying_key())
+    }
+}
+
+impl Hash for Secp256k1PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(SECP256K1_KEY_TYPE);
+        state.write(&self.to_bytes());
+    }
+}
+
+impl Display for Secp256k1PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string())
+    }
+}
+
+/// The secret key wrapper around k256 signing key
+pub struct Secp256k1SecretKey(SigningKey);
+
+impl Secp256k1SecretKey {
+    /// Sign a `data` with a private key.
+    /// The data should be a hash of the message, like a transaction hash
+    pub fn sign(&self, data: &[u8]) -> Result<Secp256k1Signature> {
+        let (signature, recovery_id): (Signature, RecoveryId) = self
+            .0
+            .sign_prehash_recoverable(data)
+            .map_err(|err| Error::from_bytes::<Secp256k1Signature>(data, err.to_string()))?;
+
+        let mut bytes = [0; SECP256K1_SIGNATURE_LENGTH];
+        bytes[..SECP256K1_SIGNATURE_LENGTH - 1].copy_from_slice(&signature.to_bytes());
+        bytes[SECP256K1_SIGNATURE_LENGTH - 1] = recovery_id.to_byte();
+        Ok(Secp256k1Signature(bytes))
+    }
+
+    /// Derives the [`Secp256k1PublicKey`] from a private key
+    pub fn public_key(&self) -> Secp256k1PublicKey {
+        Secp256k1PublicKey::from(self)
+    }
+}
+
+impl Key<SECP256K1_SECRET_KEY_LENGTH> for Secp256k1SecretKey {
+    const KEY_TYPE: &'static str = SECP256K1;
+
+    #[inline]
+    fn to_bytes(&self) -> [u8; SECP256K1_SECRET_KEY_LENGTH] {
+        self.0.to_bytes().into()
+    }
+
+    fn try_from_bytes(buf: &[u8]) -> Result<Self> {
+        SigningKey::from_slice(buf)
+            .map(Self)
+            .map_err(|err| Error::from_bytes::<Secp256k1SecretKey>(buf, err.to_string()))
+    }
+}
+
+impl BorshDeserialize for Secp256k1SecretKey {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut buf = [0; SECP256K1_SECRET_KEY_LENGTH];
+        reader.read_exact(&mut buf)?;
+        Secp256k1SecretKey::try_from_bytes(&buf)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))
+    }
+}
+
+impl BorshSerialize for Secp256k1SecretKey {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+/// The recoverable signature, the last byte is a recovery id
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Secp256k1Signature([u8; SECP256K1_SIGNATURE_LENGTH]);
+
+impl Key<SECP256K1_SIGNATURE_LENGTH> for Secp256k1Signature {
+    const KEY_TYPE: &'static str = SECP256K1;
+
+    #[inline]
+    fn to_bytes(&self) -> [u8; SECP256K1_SIGNATURE_LENGTH] {
+        self.0
+    }
+
+    fn try_from_bytes(buf: &[u8]) -> Result<Self> {
+        <[u8; SECP256K1_SIGNATURE_LENGTH]>::try_from(buf)
+            .map(Self)
+            .map_err(|err| Error::from_bytes::<Secp256k1Signature>(buf, err.to_string()))
+    }
+}
+
+impl BorshDeserialize for Secp256k1Signature {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        read_key_type(reader)?;
+        let mut buf = [0; SECP256K1_SIGNATURE_LENGTH];
+        reader.read_exact(&mut buf)?;
+        Ok(Secp256k1Signature(buf))
+    }
+}
+
+impl BorshSerialize for Secp256k1Signature {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&SECP256K1_KEY_TYPE, writer)?;
+        writer.write_all(&self.0)
+    }
+}
+
+impl Display for Secp256k1Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string())
+    }
+}
+
+/// Reads the key type discriminant and checks, that it's secp256k1
+fn read_key_type<R: Read>(reader: &mut R) -> std::io::Result<()> {
+    match u8::deserialize_reader(reader)? {
+        SECP256K1_KEY_TYPE => Ok(()),
+        _ => Err(IoError::new(
+            ErrorKind::InvalidData,
+            "The key type isn't secp256k1",
+        )),
+    }
+}
+
+serde_impl!(Secp256k1PublicKey);
+serde_impl!(Secp256k1SecretKey);
+serde_impl!(Secp256k1Signature);
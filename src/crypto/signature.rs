@@ -0,0 +1,168 @@
+//! ### Signature of a transaction
+//! ---
+//! Transactions could be signed either with ed25519 or secp256k1 keys,
+//! so the signature is stored with a key type, the same way as the Near protocol does
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::{
+    fmt::Display,
+    io::{Error as IoError, ErrorKind, Read},
+    str::FromStr,
+};
+
+use super::{
+    ed25519::{Ed25519Signature, ED25519_SIGNATURE_LENGTH},
+    public_key::NearPublicKey,
+    secp256k1::{Secp256k1Signature, SECP256K1_SIGNATURE_LENGTH},
+    split_encoded_str, Error, Key, Result, ED25519, SECP256K1,
+};
+
+/// The key type discriminant of ed25519, that is used by the Near protocol
+const ED25519_KEY_TYPE: u8 = 0;
+/// The key type discriminant of secp256k1, that is used by the Near protocol
+const SECP256K1_KEY_TYPE: u8 = 1;
+
+/// The signature of a transaction, it could be either ed25519 or secp256k1 signature.
+///
+/// The string representation is prefixed with a key type, like `ed25519:...` or `secp256k1:...`,
+/// the [Borsh](https://borsh.io/) representation is prefixed with a key type byte
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NearSignature {
+    /// ed25519 signature
+    Ed25519(Ed25519Signature),
+    /// secp256k1 signature
+    Secp256k1(Secp256k1Signature),
+}
+
+impl NearSignature {
+    /// Returns an [`Ed25519Signature`], if it's an ed25519 signature
+    pub fn as_ed25519(&self) -> Option<&Ed25519Signature> {
+        match self {
+            Self::Ed25519(signature) => Some(signature),
+            Self::Secp256k1(_) => None,
+        }
+    }
+
+    /// Returns a [`Secp256k1Signature`], if it's a secp256k1 signature
+    pub fn as_secp256k1(&self) -> Option<&Secp256k1Signature> {
+        match self {
+            Self::Ed25519(_) => None,
+            Self::Secp256k1(signature) => Some(signature),
+        }
+    }
+
+    /// Returns a key type, that is a prefix of the string representation
+    pub fn key_type(&self) -> &'static str {
+        match self {
+            Self::Ed25519(_) => ED25519,
+            Self::Secp256k1(_) => SECP256K1,
+        }
+    }
+
+    /// Verifies the signature of the data with the public key of the same type
+    pub fn verify(&self, data: &[u8], public_key: &NearPublicKey) -> Result<()> {
+        match (self, public_key) {
+            (Self::Ed25519(signature), NearPublicKey::Ed25519(key)) => key.verify(data, signature),
+            (Self::Secp256k1(signature), NearPublicKey::Secp256k1(key)) => {
+                key.verify(data, signature)
+            }
+            _ => Err(Error::Verification(self.to_string())),
+        }
+    }
+}
+
+impl From<Ed25519Signature> for NearSignature {
+    fn from(signature: Ed25519Signature) -> Self {
+        Self::Ed25519(signature)
+    }
+}
+
+impl From<Secp256k1Signature> for NearSignature {
+    fn from(signature: Secp256k1Signature) -> Self {
+        Self::Secp256k1(signature)
+    }
+}
+
+impl PartialEq<Ed25519Signature> for NearSignature {
+    fn eq(&self, other: &Ed25519Signature) -> bool {
+        self.as_ed25519() == Some(other)
+    }
+}
+
+impl PartialEq<Secp256k1Signature> for NearSignature {
+    fn eq(&self, other: &Secp256k1Signature) -> bool {
+        self.as_secp256k1() == Some(other)
+    }
+}
+
+impl Display for NearSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ed25519(signature) => write!(f, "{}", signature.string()),
+            Self::Secp256k1(signature) => write!(f, "{}", signature.string()),
+        }
+    }
+}
+
+impl FromStr for NearSignature {
+    type Err = Error;
+
+    fn from_str(signature: &str) -> Result<Self> {
+        match split_encoded_str(signature)? {
+            (ED25519, ..) => Ed25519Signature::from_string(signature).map(Self::Ed25519),
+            (SECP256K1, ..) => Secp256k1Signature::from_string(signature).map(Self::Secp256k1),
+            _ => Err(Error::UnknownKeyType(signature.to_owned())),
+        }
+    }
+}
+
+impl BorshSerialize for NearSignature {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Both of the signatures are serialized with a key type byte
+        match self {
+            Self::Ed25519(signature) => BorshSerialize::serialize(signature, writer),
+            Self::Secp256k1(signature) => BorshSerialize::serialize(signature, writer),
+        }
+    }
+}
+
+impl BorshDeserialize for NearSignature {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The exact amount of bytes is read, so the signature could be followed by other fields
+        let signature = match u8::deserialize_reader(reader)? {
+            ED25519_KEY_TYPE => {
+                let mut buf = [0; ED25519_SIGNATURE_LENGTH];
+                reader.read_exact(&mut buf)?;
+                Ed25519Signature::try_from_bytes(&buf).map(Self::Ed25519)
+            }
+            SECP256K1_KEY_TYPE => {
+                let mut buf = [0; SECP256K1_SIGNATURE_LENGTH];
+                reader.read_exact(&mut buf)?;
+                Secp256k1Signature::try_from_bytes(&buf).map(Self::Secp256k1)
+            }
+            key_type => Err(Error::UnknownKeyType(key_type.to_string())),
+        };
+
+        signature.map_err(|err| IoError::new(ErrorKind::InvalidData, err))
+    }
+}
+
+impl serde::Serialize for NearSignature {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NearSignature {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        NearSignature::from_str(&s)
+            .map_err(|err| serde::de::Error::custom(format!("Deserialization failed: `{}`", err)))
+    }
+}
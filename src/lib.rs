@@ -6,33 +6,81 @@ pub mod client;
 #[doc(hidden)]
 pub mod components;
 pub mod crypto;
+/// Verification of the proofs, that are provided by the RPC for the light clients.
+pub mod light_client;
 #[doc(hidden)]
 pub mod near_primitives_light;
+pub mod nft;
 pub(crate) mod rpc;
+#[cfg(feature = "testnet")]
+pub mod testnet;
+pub mod tokens;
 #[doc(hidden)]
 pub mod utils;
 
+pub use rpc::transport;
+
 use std::fmt::Display;
 
 pub use near_primitives_core as core;
 pub use near_units;
 
-type Result<T> = std::result::Result<T, Error>;
+/// A specialized [`Result`](std::result::Result) type with the crate [`Error`]
+pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Parse's human-readable string into [Gas](core::types::Gas)
 ///
 /// # Panic
-/// If can't correctly parse input into [Gas](core::types::Gas)
+/// If can't correctly parse input into [Gas](core::types::Gas),
+/// use [`try_gas`] for the input that isn't a literal
 pub fn gas(input: &str) -> core::types::Gas {
-    near_units::gas::parse(input).unwrap() as u64
+    try_gas(input).unwrap()
 }
 
 /// Parse's human-readable string into [Balance](core::types::Balance)
 ///
 /// # Panic
-/// If can't correctly parse input into [Balance](core::types::Balance)
+/// If can't correctly parse input into [Balance](core::types::Balance),
+/// use [`try_near`] for the input that isn't a literal
 pub fn near(input: &str) -> core::types::Balance {
-    near_units::near::parse(input).unwrap()
+    try_near(input).unwrap()
+}
+
+/// Parse's human-readable string into [Gas](core::types::Gas), like `"30 Tgas"`
+///
+/// ## Errors
+///
+/// [`ParseUnitError`], if the input isn't a valid amount of gas or it doesn't fit into [Gas](core::types::Gas)
+///
+/// ## Example
+///
+/// ```rust
+/// use near_client::prelude::*;
+///
+/// assert_eq!(try_gas("30 Tgas").unwrap(), 30_000_000_000_000);
+/// assert!(try_gas("thirty Tgas").is_err());
+/// ```
+pub fn try_gas(input: &str) -> Result<core::types::Gas, ParseUnitError> {
+    let gas = near_units::gas::parse(input).map_err(|err| ParseUnitError::new(input, err))?;
+    core::types::Gas::try_from(gas).map_err(|err| ParseUnitError::new(input, err))
+}
+
+/// Parse's human-readable string into [Balance](core::types::Balance), like `"1.5 N"`
+///
+/// ## Errors
+///
+/// [`ParseUnitError`], if the input isn't a valid amount of Near tokens
+///
+/// ## Example
+///
+/// ```rust
+/// use near_client::prelude::*;
+///
+/// assert_eq!(try_near("1 Near").unwrap(), 1_000_000_000_000_000_000_000_000);
+/// assert!(try_near("one Near").is_err());
+/// ```
+pub fn try_near(input: &str) -> Result<core::types::Balance, ParseUnitError> {
+    near_units::near::parse(input).map_err(|err| ParseUnitError::new(input, err))
 }
 
 /// Converts a Near token amount to a human-readable format.
@@ -86,6 +134,124 @@ pub fn gas_to_human(gas: core::types::Gas) -> String {
     near_units::gas::to_human(gas as u128)
 }
 
+/// Parse's a base58 transaction hash, like the one that is shown by explorers,
+/// into a [CryptoHash](core::hash::CryptoHash)
+///
+/// ## Errors
+///
+/// [`Error::ParseTxHash`], if the input isn't a valid base58 encoded hash
+///
+/// ## Example
+///
+/// ```rust
+/// use near_client::prelude::*;
+///
+/// let hash = parse_tx_hash("9FtHUFBQsZ2MG77K3x3MJ9wjX3UT8zE1TczCrhZEcG8U").unwrap();
+/// assert_eq!(hash.to_string(), "9FtHUFBQsZ2MG77K3x3MJ9wjX3UT8zE1TczCrhZEcG8U");
+/// assert!(parse_tx_hash("not a hash").is_err());
+/// ```
+pub fn parse_tx_hash(input: &str) -> Result<core::hash::CryptoHash> {
+    input
+        .parse::<core::hash::CryptoHash>()
+        .map_err(|err| Error::ParseTxHash {
+            input: input.to_owned(),
+            cause: err.to_string(),
+        })
+}
+
+/// An amount of Near tokens, that is stored in yocto Near.
+/// Unlike a raw [Balance](core::types::Balance) it can't be confused with an amount in Near
+///
+/// ## Example
+///
+/// ```rust
+/// use near_client::prelude::*;
+///
+/// let amount = NearToken::from_near_str("1.5 N").unwrap();
+/// assert_eq!(amount.as_yocto(), 1_500_000_000_000_000_000_000_000);
+/// assert_eq!(amount.to_string(), "1.5 N");
+///
+/// let fee = NearToken::from_yocto(500_000_000_000_000_000_000_000);
+/// assert_eq!(amount.checked_sub(fee), Some(NearToken::from_yocto(1_000_000_000_000_000_000_000_000)));
+/// assert!(NearToken::try_from("one Near").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NearToken(core::types::Balance);
+
+impl NearToken {
+    /// Creates an amount from yocto Near
+    pub const fn from_yocto(yocto: core::types::Balance) -> Self {
+        Self(yocto)
+    }
+
+    /// Parse's human-readable string, like `"1.5 N"` or `"1 Near"`, into an amount
+    ///
+    /// ## Errors
+    ///
+    /// [`ParseUnitError`], if the input isn't a valid amount of Near tokens
+    pub fn from_near_str(input: &str) -> Result<Self, ParseUnitError> {
+        try_near(input).map(Self)
+    }
+
+    /// Returns the amount in yocto Near
+    pub const fn as_yocto(&self) -> core::types::Balance {
+        self.0
+    }
+
+    /// Adds two amounts, returns [`None`] on overflow
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+
+    /// Subtracts an amount, returns [`None`] on underflow
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for NearToken {
+    type Error = ParseUnitError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::from_near_str(input)
+    }
+}
+
+impl From<NearToken> for core::types::Balance {
+    fn from(amount: NearToken) -> Self {
+        amount.0
+    }
+}
+
+impl Display for NearToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", near_to_human(self.0))
+    }
+}
+
+/// Describes an error of parsing a human-readable amount of units
+#[derive(Debug, thiserror::Error)]
+#[error("Couldn't parse [\"{input}\"], cause: [\"{cause}\"]")]
+pub struct ParseUnitError {
+    input: String,
+    cause: String,
+}
+
+impl ParseUnitError {
+    fn new(input: &str, cause: impl std::fmt::Debug) -> Self {
+        Self {
+            input: input.to_owned(),
+            cause: format!("{cause:?}"),
+        }
+    }
+}
+
 /// Client prelude.
 /// All the frequently used API
 pub mod prelude {
@@ -93,14 +259,25 @@ pub mod prelude {
     pub use super::components::*;
     pub use super::core::{
         account::{AccessKeyPermission, Account, FunctionCallPermission},
+        hash::CryptoHash,
         types::{AccountId, Balance, Gas, Nonce},
     };
     pub use super::crypto::prelude::*;
     pub use super::near_primitives_light::{
         errors::{self as transaction_errors},
-        types::Finality,
+        types::{
+            AccountWithPublicKey, BlockId, BlockReference, ChunkReference, Finality,
+            StateChangeCause, StateChangeValue, StateChangeWithCause, StateChanges,
+            StateChangesRequest, TransactionOrReceiptId,
+        },
+        views::{StatusResponse, StatusSyncInfo},
+    };
+    pub use super::nft::NftContractMetadata;
+    pub use super::tokens::FtMetadata;
+    pub use super::{
+        gas, gas_to_human, near, near_to_human, parse_tx_hash, try_gas, try_near, Error, NearToken,
+        ParseUnitError, Result,
     };
-    pub use super::{gas, gas_to_human, near, near_to_human};
     pub use transaction_errors::*;
 }
 
@@ -112,6 +289,9 @@ pub enum Error {
     #[error("Failed to create a signer, cause [\"{0}\"]")]
     CreateSigner(crypto::Error),
     #[doc(hidden)]
+    #[error("Failed to read a credentials file, cause [\"{0}\"]")]
+    ReadCredentials(std::io::Error),
+    #[doc(hidden)]
     #[error("Transaction not started, logs: [\"{0:?}\"]")]
     TxNotStarted(Box<Vec<String>>),
     #[doc(hidden)]
@@ -163,6 +343,12 @@ pub enum Error {
     #[error("Couldn't deserialize a view response, cause [\"{0}\"]")]
     DeserializeResponseView(serde_json::Error),
     #[doc(hidden)]
+    #[error("Couldn't deserialize a borsh view call result, cause [\"{0}\"]")]
+    DeserializeViewCallBorsh(std::io::Error),
+    #[doc(hidden)]
+    #[error("Couldn't deserialize a contract state value, cause [\"{0}\"]")]
+    DeserializeStateValue(std::io::Error),
+    #[doc(hidden)]
     #[error("Couldn't deserialize a block, cause: [\"{0}\"]")]
     DeserializeBlock(serde_json::Error),
     #[doc(hidden)]
@@ -171,6 +357,75 @@ pub enum Error {
     #[doc(hidden)]
     #[error("Can't deserialize an access key response, cause: [\"{0}\"]")]
     DeserializeAccessKeyListViewCall(serde_json::Error),
+    #[doc(hidden)]
+    #[error("Deployed code hash [\"{actual}\"] doesn't match the expected [\"{expected}\"]")]
+    CodeHashMismatch {
+        expected: core::hash::CryptoHash,
+        actual: core::hash::CryptoHash,
+    },
+    #[doc(hidden)]
+    #[error("The beneficiary [\"{0}\"] can't be the same as the deleted account")]
+    InvalidBeneficiary(core::types::AccountId),
+    #[doc(hidden)]
+    #[error("Account [\"{0}\"] is staking and can't be deleted")]
+    AccountIsStaking(core::types::AccountId),
+    #[doc(hidden)]
+    #[error("The state of account [\"{account_id}\"] is too large [\"{storage_usage}\"] and can't be deleted")]
+    AccountStateTooLarge {
+        account_id: core::types::AccountId,
+        storage_usage: core::types::StorageUsage,
+    },
+    #[doc(hidden)]
+    #[error("Account [\"{0}\"] doesn't have a full access key")]
+    NoFullAccessKey(core::types::AccountId),
+    #[doc(hidden)]
+    #[error("Transfer of zero tokens to [\"{0}\"]")]
+    ZeroTransfer(core::types::AccountId),
+    #[doc(hidden)]
+    #[error("Transaction actions exceed the protocol limits, cause: [\"{0}\"]")]
+    BatchLimitsExceeded(prelude::ActionsValidationError),
+    #[doc(hidden)]
+    #[error("Transaction [\"{0}\"] isn't finished in time")]
+    AwaitTransactionTimeout(core::hash::CryptoHash),
+    #[doc(hidden)]
+    #[error("Receipt [\"{0}\"] isn't executed in time")]
+    AwaitReceiptTimeout(core::hash::CryptoHash),
+    #[doc(hidden)]
+    #[error("Request isn't finished in [{0:?}]")]
+    RequestTimeout(std::time::Duration),
+    #[doc(hidden)]
+    #[error("Delegate action can't contain another delegate action")]
+    NestedDelegateAction,
+    #[doc(hidden)]
+    #[error("View function call failed with an error: [\"{error}\"], logs: [\"{logs:?}\"]")]
+    ViewFunctionCall { error: String, logs: Vec<String> },
+    #[doc(hidden)]
+    #[error("Access key can't be used for the transaction, cause: [\"{0}\"]")]
+    InvalidAccessKey(prelude::InvalidAccessKeyError),
+    #[doc(hidden)]
+    #[error("Couldn't parse a transaction hash [\"{input}\"], cause: [\"{cause}\"]")]
+    ParseTxHash { input: String, cause: String },
+    #[doc(hidden)]
+    #[error("The operation was cancelled")]
+    Cancelled,
+    #[doc(hidden)]
+    #[error("Retry backoff factor [{0}] has to be finite and not less than 1")]
+    InvalidRetryFactor(f64),
+    #[cfg(feature = "testnet")]
+    #[doc(hidden)]
+    #[error("Testnet faucet request failed, cause: [\"{0}\"]")]
+    Faucet(rpc::Error),
+}
+
+impl Error {
+    /// Logs of a transaction, that failed or isn't started, or of a failed view function call
+    pub fn logs(&self) -> Option<&[String]> {
+        match self {
+            Self::TxExecution(_, logs) | Self::TxNotStarted(logs) => Some(logs.as_slice()),
+            Self::ViewFunctionCall { logs, .. } => Some(logs.as_slice()),
+            _ => None,
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -0,0 +1,72 @@
+use crate::{
+    core::hash::CryptoHash,
+    near_primitives_light::{
+        merkle::{verify_hash, MerklePathItem},
+        views::ExecutionOutcomeWithIdView,
+    },
+};
+
+/// Verifies, that the execution outcome is included into the outcome root,
+/// like the root of a shard or the one from the light client block header
+///
+/// ## Arguments
+///
+/// - **outcome** - [`ExecutionOutcomeWithIdView`] of a transaction or a receipt
+/// - **proof** - Merkle path from the outcome to the root
+/// - **expected_root** - The root, that is known to the light client
+pub fn verify_outcome_proof(
+    outcome: &ExecutionOutcomeWithIdView,
+    proof: &[MerklePathItem],
+    expected_root: CryptoHash,
+) -> bool {
+    let outcome_hash = CryptoHash::hash_borsh(outcome.to_hashes());
+    verify_hash(expected_root, proof, outcome_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_outcome_proof;
+    use crate::{
+        core::hash::CryptoHash,
+        near_primitives_light::{
+            merkle::{combine_hash, Direction, MerklePathItem},
+            views::{ExecutionOutcomeView, ExecutionOutcomeWithIdView, ExecutionStatusView},
+        },
+    };
+
+    #[test]
+    fn outcome_proof() {
+        let outcome = ExecutionOutcomeWithIdView {
+            proof: vec![],
+            block_hash: CryptoHash::default(),
+            id: CryptoHash::hash_bytes(b"receipt"),
+            outcome: ExecutionOutcomeView {
+                logs: vec!["log".to_owned()],
+                receipt_ids: vec![],
+                gas_burnt: 1,
+                tokens_burnt: 1,
+                executor_id: "alice.test.near".parse().unwrap(),
+                status: ExecutionStatusView::SuccessValue(vec![]),
+                metadata: Default::default(),
+            },
+        };
+        let outcome_hash = CryptoHash::hash_borsh(outcome.to_hashes());
+        let sibling = CryptoHash::hash_bytes(b"sibling");
+        let proof = vec![MerklePathItem {
+            hash: sibling,
+            direction: Direction::Left,
+        }];
+
+        assert!(verify_outcome_proof(
+            &outcome,
+            &proof,
+            combine_hash(&sibling, &outcome_hash)
+        ));
+        assert!(!verify_outcome_proof(
+            &outcome,
+            &proof,
+            combine_hash(&outcome_hash, &sibling)
+        ));
+        assert!(!verify_outcome_proof(&outcome, &[], sibling));
+    }
+}
@@ -8,13 +8,56 @@ use std::fmt::{Debug, Display};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-/// Container for TxExecutionError, when error comes not from TransactionOutcome
+/// Container for TxExecutionError, when error comes not from TransactionOutcome.
+/// It's the envelope of the node error JSON, `{"TxExecutionError": {...}}`,
+/// so the errors, that are received from other services, like a relayer API,
+/// could be parsed into the [`TxExecutionError`]
+///
+/// ```
+/// use near_client::prelude::*;
+/// use serde_json::json;
+///
+/// let container = TxExecutionErrorContainer::try_from(json!({
+///     "TxExecutionError": {
+///         "InvalidTxError": { "InvalidNonce": { "tx_nonce": 1, "ak_nonce": 2 } }
+///     }
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(
+///     container.tx_execution_error(),
+///     &TxExecutionError::InvalidTxError(InvalidTxError::InvalidNonce {
+///         tx_nonce: 1,
+///         ak_nonce: 2
+///     })
+/// );
+/// ```
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct TxExecutionErrorContainer {
     pub tx_execution_error: TxExecutionError,
 }
 
+impl TxExecutionErrorContainer {
+    /// The wrapped [`TxExecutionError`]
+    pub const fn tx_execution_error(&self) -> &TxExecutionError {
+        &self.tx_execution_error
+    }
+
+    /// Unwraps the [`TxExecutionError`]
+    pub fn into_tx_execution_error(self) -> TxExecutionError {
+        self.tx_execution_error
+    }
+}
+
+impl TryFrom<serde_json::Value> for TxExecutionErrorContainer {
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
 /// Error returned in the ExecutionOutcome in case of failure
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum TxExecutionError {
@@ -24,6 +67,62 @@ pub enum TxExecutionError {
     InvalidTxError(InvalidTxError),
 }
 
+/// The prefix of a contract panic message, that is reported as an execution error
+const GUEST_PANIC_PREFIX: &str = "Smart contract panicked: ";
+
+impl TxExecutionError {
+    /// The transaction nonce isn't greater than the access key nonce
+    pub fn is_invalid_nonce(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidTxError(InvalidTxError::InvalidNonce { .. })
+        )
+    }
+
+    /// The signer account doesn't have enough balance to cover the transaction cost
+    pub fn is_not_enough_balance(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidTxError(InvalidTxError::NotEnoughBalance { .. })
+        )
+    }
+
+    /// The transaction block hash is too old
+    pub fn is_expired(&self) -> bool {
+        matches!(self, Self::InvalidTxError(InvalidTxError::Expired))
+    }
+
+    /// The called method isn't exported by the contract
+    pub fn is_method_not_found(&self) -> bool {
+        matches!(
+            self.function_call_error(),
+            Some(FunctionCallError::MethodResolveError(
+                MethodResolveError::MethodNotFound
+            ))
+        )
+    }
+
+    /// Returns the panic message, if the contract panicked
+    pub fn is_guest_panic(&self) -> Option<&str> {
+        match self.function_call_error()? {
+            FunctionCallError::HostError(HostError::GuestPanic { panic_msg }) => Some(panic_msg),
+            // The recent nodes report the panic as a message of the execution error
+            FunctionCallError::ExecutionError(msg) => msg.strip_prefix(GUEST_PANIC_PREFIX),
+            _ => None,
+        }
+    }
+
+    fn function_call_error(&self) -> Option<&FunctionCallError> {
+        match self {
+            Self::ActionError(ActionError {
+                kind: ActionErrorKind::FunctionCallError(err),
+                ..
+            }) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 impl std::error::Error for TxExecutionError {}
 
 impl Display for TxExecutionError {
@@ -99,7 +198,7 @@ pub enum InvalidAccessKeyError {
     /// The access key identified by the `public_key` doesn't exist for the account
     AccessKeyNotFound {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     /// Transaction `receiver_id` doesn't match the access key receiver_id
     ReceiverMismatch {
@@ -113,7 +212,7 @@ pub enum InvalidAccessKeyError {
     /// Access Key does not have enough allowance to cover transaction cost
     NotEnoughAllowance {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
         #[serde(with = "dec_format")]
         allowance: Balance,
         #[serde(with = "dec_format")]
@@ -153,7 +252,7 @@ pub enum ActionsValidationError {
     /// The length of the arguments exceeded the limit in a Function Call action.
     FunctionCallArgumentsLengthExceeded { length: u64, limit: u64 },
     /// An attempt to stake with a public key that is not convertible to ristretto.
-    UnsuitableStakingKey { public_key: Ed25519PublicKey },
+    UnsuitableStakingKey { public_key: NearPublicKey },
     /// The attached amount of gas in a FunctionCall action has to be a positive number.
     FunctionCallZeroAttachedGas,
 }
@@ -501,12 +600,12 @@ pub enum ActionErrorKind {
     /// Account tries to remove an access key that doesn't exist
     DeleteKeyDoesNotExist {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     /// The public key is already used for an existing access key
     AddKeyAlreadyExists {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     /// Account is staking and can not be deleted
     DeleteAccountStaking { account_id: AccountId },
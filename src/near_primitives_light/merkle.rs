@@ -0,0 +1,47 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_primitives_core::hash::CryptoHash;
+use serde::{Deserialize, Serialize};
+
+pub type MerkleHash = CryptoHash;
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MerklePathItem {
+    pub hash: MerkleHash,
+    pub direction: Direction,
+}
+
+pub type MerklePath = Vec<MerklePathItem>;
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub fn combine_hash(hash1: &MerkleHash, hash2: &MerkleHash) -> MerkleHash {
+    CryptoHash::hash_borsh((hash1, hash2))
+}
+
+/// Verify merkle path for given item and corresponding path.
+pub fn verify_path<T: BorshSerialize>(root: MerkleHash, path: &[MerklePathItem], item: T) -> bool {
+    verify_hash(root, path, CryptoHash::hash_borsh(item))
+}
+
+pub fn verify_hash(root: MerkleHash, path: &[MerklePathItem], item_hash: MerkleHash) -> bool {
+    compute_root_from_path(path, item_hash) == root
+}
+
+pub fn compute_root_from_path(path: &[MerklePathItem], item_hash: MerkleHash) -> MerkleHash {
+    let mut res = item_hash;
+    for item in path {
+        match item.direction {
+            Direction::Left => {
+                res = combine_hash(&item.hash, &res);
+            }
+            Direction::Right => {
+                res = combine_hash(&res, &item.hash);
+            }
+        }
+    }
+    res
+}
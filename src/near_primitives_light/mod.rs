@@ -2,6 +2,7 @@
 //! It's a reduced implementation of near primitives.
 
 pub mod errors;
+pub mod merkle;
 pub mod receipt;
 pub mod transaction;
 pub mod types;
@@ -51,7 +51,7 @@ impl Receipt {
 
             receipt: ReceiptEnum::Action(ActionReceipt {
                 signer_id: "system".parse().unwrap(),
-                signer_public_key: Ed25519PublicKey::try_from_bytes(&[0; 32]).unwrap(),
+                signer_public_key: Ed25519PublicKey::try_from_bytes(&[0; 32]).unwrap().into(),
                 gas_price: 0,
                 output_data_receivers: vec![],
                 input_data_ids: vec![],
@@ -69,7 +69,7 @@ impl Receipt {
     pub fn new_gas_refund(
         receiver_id: &AccountId,
         refund: Balance,
-        signer_public_key: Ed25519PublicKey,
+        signer_public_key: NearPublicKey,
     ) -> Self {
         Receipt {
             predecessor_id: "system".parse().unwrap(),
@@ -102,7 +102,7 @@ pub struct ActionReceipt {
     /// A signer of the original transaction
     pub signer_id: AccountId,
     /// An access key which was used to sign the original transaction
-    pub signer_public_key: Ed25519PublicKey,
+    pub signer_public_key: NearPublicKey,
     /// A gas_price which has been used to buy gas in the original transaction
     #[serde(with = "dec_format")]
     pub gas_price: Balance,
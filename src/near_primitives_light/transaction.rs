@@ -22,13 +22,18 @@ use std::{
 
 pub type LogEntry = String;
 
+/// The smallest discriminant of the on-chain messages, see [NEP-461](https://github.com/near/NEPs/pull/461)
+const MIN_ON_CHAIN_DISCRIMINANT: u32 = 1 << 30;
+/// The number of the meta transactions standard
+const NEP_366_META_TRANSACTIONS: u32 = 366;
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 pub struct Transaction {
     /// An account on which behalf transaction is signed
     pub signer_id: AccountId,
     /// A public key of the access key which was used to sign an account.
     /// Access key holds permissions for calling certain kinds of actions.
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
     /// Nonce is used to determine order of transaction in the pool.
     /// It increments for a combination of `signer_id` and `public_key`
     pub nonce: Nonce,
@@ -99,12 +104,36 @@ pub struct DelegateAction {
     /// The maximal height of the block in the blockchain below which the given DelegateAction is valid.
     pub max_block_height: BlockHeight,
     /// Public key used to sign this delegated action.
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
+}
+
+impl DelegateAction {
+    /// Computes a hash of the delegate action for signing.
+    /// The action is prefixed with the NEP-366 discriminant, so it can't be confused with a transaction
+    pub fn get_nep461_hash(&self) -> CryptoHash {
+        let mut bytes = (MIN_ON_CHAIN_DISCRIMINANT + NEP_366_META_TRANSACTIONS)
+            .try_to_vec()
+            .expect("Failed to serialize");
+        bytes.extend(self.try_to_vec().expect("Failed to serialize"));
+        hash(&bytes)
+    }
 }
 
 #[derive(Serialize, BorshSerialize, BorshDeserialize, Deserialize, PartialEq, Eq, Clone, Debug)]
 pub struct NonDelegateAction(Action);
 
+impl TryFrom<Action> for NonDelegateAction {
+    type Error = Action;
+
+    /// Fails with the original action, if it's a delegate one
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        match action {
+            Action::Delegate(_) => Err(action),
+            action => Ok(Self(action)),
+        }
+    }
+}
+
 impl From<NonDelegateAction> for Action {
     fn from(action: NonDelegateAction) -> Self {
         action.0
@@ -114,7 +143,7 @@ impl From<NonDelegateAction> for Action {
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
 pub struct SignedDelegateAction {
     pub delegate_action: DelegateAction,
-    pub signature: Ed25519Signature,
+    pub signature: NearSignature,
 }
 
 /// Create account action
@@ -198,7 +227,7 @@ pub struct StakeAction {
     #[serde(with = "dec_format")]
     pub stake: Balance,
     /// Validator key which will be used to sign transactions on behalf of signer_id
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
 }
 
 impl From<StakeAction> for Action {
@@ -210,7 +239,7 @@ impl From<StakeAction> for Action {
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
 pub struct AddKeyAction {
     /// A public key which will be associated with an access_key
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
     /// An access key with the permission
     pub access_key: AccessKey,
 }
@@ -224,7 +253,7 @@ impl From<AddKeyAction> for Action {
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
 pub struct DeleteKeyAction {
     /// A public key associated with the access_key to be deleted.
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
 }
 
 impl From<DeleteKeyAction> for Action {
@@ -248,7 +277,7 @@ impl From<DeleteAccountAction> for Action {
 #[borsh_init(init)]
 pub struct SignedTransaction {
     pub transaction: Transaction,
-    pub signature: Ed25519Signature,
+    pub signature: NearSignature,
     #[borsh_skip]
     hash: CryptoHash,
     #[borsh_skip]
@@ -256,7 +285,7 @@ pub struct SignedTransaction {
 }
 
 impl SignedTransaction {
-    pub fn new(signature: Ed25519Signature, transaction: Transaction) -> Self {
+    pub fn new(signature: NearSignature, transaction: Transaction) -> Self {
         let mut signed_tx = Self {
             signature,
             transaction,
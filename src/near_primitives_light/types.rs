@@ -1,25 +1,31 @@
+use super::views::{AccessKeyView, AccountView};
 use crate::crypto::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
 
-use near_primitives_core::{
-    account::{AccessKey, Account},
-    hash::CryptoHash,
-    serialize::dec_format,
-    types::*,
-};
+use near_primitives_core::{hash::CryptoHash, serialize::dec_format, types::*};
 
 /// Hash used by to store state root.
 pub type StateRoot = CryptoHash;
 
 /// Different types of finality.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+///
+/// String representation is the same as a serialized one.
+/// It's parsed case-insensitively from either a serialized name or a variant name, like `none` or `doom_slug`
+#[derive(
+    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default, strum::Display, strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum Finality {
     #[serde(rename = "optimistic")]
+    #[strum(to_string = "optimistic", serialize = "none")]
     None,
     #[serde(rename = "near-final")]
+    #[strum(to_string = "near-final", serialize = "doom_slug")]
     DoomSlug,
     #[serde(rename = "final")]
+    #[strum(serialize = "final")]
     #[default]
     Final,
 }
@@ -27,7 +33,7 @@ pub enum Finality {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AccountWithPublicKey {
     pub account_id: AccountId,
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
 }
 
 /// Account info for validators
@@ -59,7 +65,8 @@ pub enum StateChangeKind {
 pub type StateChangesKinds = Vec<StateChangeKind>;
 
 /// A structure used to index state changes due to transaction/receipt processing and other things.
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
 pub enum StateChangeCause {
     /// A type of update that does not get finalized. Used for verification and execution of
     /// immutable smart contract methods. Attempt to finalize a `TrieUpdate` containing such
@@ -102,7 +109,9 @@ pub struct RawStateChange {
     pub data: Option<Vec<u8>>,
 }
 
-#[derive(Debug)]
+/// Kind of the state changes, that are requested with the `EXPERIMENTAL_changes`
+#[derive(Debug, Serialize)]
+#[serde(tag = "changes_type", rename_all = "snake_case")]
 pub enum StateChangesRequest {
     AccountChanges { account_ids: Vec<AccountId> },
     SingleAccessKeyChanges { keys: Vec<AccountWithPublicKey> },
@@ -110,26 +119,31 @@ pub enum StateChangesRequest {
     ContractCodeChanges { account_ids: Vec<AccountId> },
 }
 
-#[derive(Debug)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "change")]
 pub enum StateChangeValue {
     AccountUpdate {
         account_id: AccountId,
-        account: Account,
+        #[serde(flatten)]
+        account: AccountView,
     },
     AccountDeletion {
         account_id: AccountId,
     },
     AccessKeyUpdate {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
-        access_key: AccessKey,
+        public_key: NearPublicKey,
+        access_key: AccessKeyView,
     },
     AccessKeyDeletion {
         account_id: AccountId,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     ContractCodeUpdate {
         account_id: AccountId,
+        #[serde(rename = "code_base64")]
+        #[serde_as(as = "Base64")]
         code: Vec<u8>,
     },
     ContractCodeDeletion {
@@ -150,9 +164,10 @@ impl StateChangeValue {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StateChangeWithCause {
     pub cause: StateChangeCause,
+    #[serde(flatten)]
     pub value: StateChangeValue,
 }
 
@@ -212,6 +227,19 @@ pub struct BlockChunkValidatorStats {
     pub chunk_stats: ValidatorStats,
 }
 
+/// Reference to the chunk, by its hash or by the block and the shard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChunkReference {
+    BlockShardId {
+        block_id: BlockId,
+        shard_id: ShardId,
+    },
+    ChunkHash {
+        chunk_id: CryptoHash,
+    },
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum TransactionOrReceiptId {
@@ -230,3 +258,35 @@ pub enum CompiledContract {
     CompileModuleError,
     Code(Vec<u8>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Finality;
+    use std::str::FromStr;
+
+    #[test]
+    fn finality_string_matches_serde() {
+        for finality in [Finality::None, Finality::DoomSlug, Finality::Final] {
+            let serialized = serde_json::to_value(&finality).unwrap();
+            assert_eq!(serialized.as_str().unwrap(), finality.to_string());
+            assert_eq!(Finality::from_str(&finality.to_string()).unwrap(), finality);
+        }
+
+        assert!(Finality::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn finality_from_variant_name() {
+        assert_eq!(Finality::from_str("none").unwrap(), Finality::None);
+        assert_eq!(Finality::from_str("Optimistic").unwrap(), Finality::None);
+        assert_eq!(Finality::from_str("doom_slug").unwrap(), Finality::DoomSlug);
+        assert_eq!(
+            Finality::from_str("NEAR-FINAL").unwrap(),
+            Finality::DoomSlug
+        );
+        assert_eq!(Finality::from_str("Final").unwrap(), Finality::Final);
+
+        assert_eq!(Finality::None.to_string(), "optimistic");
+        assert_eq!(Finality::DoomSlug.to_string(), "near-final");
+    }
+}
@@ -1,4 +1,4 @@
-use super::{errors::TxExecutionError, receipt::*, transaction::*, types::*};
+use super::{errors::TxExecutionError, merkle::MerklePath, receipt::*, transaction::*, types::*};
 use crate::crypto::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use chrono::DateTime;
@@ -37,6 +37,17 @@ pub struct AccountView {
     pub storage_paid_at: BlockHeight,
 }
 
+impl AccountView {
+    /// Compares two account views by the meaningful fields only,
+    /// the deprecated `storage_paid_at` is ignored
+    pub fn balances_eq(&self, other: &AccountView) -> bool {
+        self.amount == other.amount
+            && self.locked == other.locked
+            && self.code_hash == other.code_hash
+            && self.storage_usage == other.storage_usage
+    }
+}
+
 /// A view of the contract code.
 #[serde_as]
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
@@ -156,6 +167,33 @@ pub struct AccessKeyView {
     pub permission: AccessKeyPermissionView,
 }
 
+impl AccessKeyView {
+    /// Checks whether the access key is allowed to call the `method` of the `receiver_id` contract.
+    /// A full access key permits any call, a function call key with empty `method_names` permits any method
+    pub fn permits(&self, receiver_id: &AccountId, method: &str) -> bool {
+        match &self.permission {
+            AccessKeyPermissionView::FullAccess => true,
+            AccessKeyPermissionView::FunctionCall {
+                receiver_id: allowed_receiver_id,
+                method_names,
+                ..
+            } => {
+                allowed_receiver_id == receiver_id.as_str()
+                    && (method_names.is_empty() || method_names.iter().any(|name| name == method))
+            }
+        }
+    }
+
+    /// The remaining allowance of a function call key.
+    /// Returns [`None`] for a full access key or a function call key with an unlimited allowance
+    pub fn allowance(&self) -> Option<Balance> {
+        match &self.permission {
+            AccessKeyPermissionView::FunctionCall { allowance, .. } => *allowance,
+            AccessKeyPermissionView::FullAccess => None,
+        }
+    }
+}
+
 impl From<AccessKey> for AccessKeyView {
     fn from(access_key: AccessKey) -> Self {
         Self {
@@ -199,7 +237,7 @@ pub struct AccessKeyListView {
     serde::Deserialize,
 )]
 pub struct KeysView {
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
     pub access_key: AccessKey,
 }
 
@@ -217,7 +255,7 @@ pub struct QueryError {
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct AccessKeyInfoView {
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
     pub access_key: AccessKeyView,
 }
 
@@ -446,21 +484,21 @@ pub enum ActionView {
     Stake {
         #[serde(with = "dec_format")]
         stake: Balance,
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     AddKey {
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
         access_key: AccessKeyView,
     },
     DeleteKey {
-        public_key: Ed25519PublicKey,
+        public_key: NearPublicKey,
     },
     DeleteAccount {
         beneficiary_id: AccountId,
     },
     Delegate {
         delegate_action: DelegateAction,
-        signature: Ed25519Signature,
+        signature: NearSignature,
     },
 }
 
@@ -563,11 +601,11 @@ impl TryFrom<ActionView> for Action {
 )]
 pub struct SignedTransactionView {
     pub signer_id: AccountId,
-    pub public_key: Ed25519PublicKey,
+    pub public_key: NearPublicKey,
     pub nonce: Nonce,
     pub receiver_id: AccountId,
     pub actions: Vec<ActionView>,
-    pub signature: Ed25519Signature,
+    pub signature: NearSignature,
     pub hash: CryptoHash,
 }
 
@@ -935,6 +973,8 @@ impl ExecutionOutcomeView {
     serde::Deserialize,
 )]
 pub struct ExecutionOutcomeWithIdView {
+    #[serde(default)]
+    pub proof: MerklePath,
     pub block_hash: CryptoHash,
     pub id: CryptoHash,
     pub outcome: ExecutionOutcomeView,
@@ -977,6 +1017,47 @@ pub struct FinalExecutionOutcomeView {
     pub receipts_outcome: Vec<ExecutionOutcomeWithIdView>,
 }
 
+impl FinalExecutionOutcomeView {
+    /// The amount of gas burnt by the transaction and all of its receipts
+    pub fn total_gas_burnt(&self) -> Gas {
+        self.outcomes().map(|it| it.outcome.gas_burnt).sum()
+    }
+
+    /// The amount of tokens burnt by the transaction and all of its receipts
+    pub fn total_tokens_burnt(&self) -> Balance {
+        self.outcomes().map(|it| it.outcome.tokens_burnt).sum()
+    }
+
+    /// The gas profiles of the transaction and all of its receipts, concatenated in order of execution.
+    /// Returns [`None`], if none of the outcomes has a profile
+    pub fn gas_profile(&self) -> Option<Vec<CostGasUsed>> {
+        self.outcomes()
+            .filter_map(|it| it.outcome.metadata.gas_profile.as_ref())
+            .fold(None, |profile: Option<Vec<CostGasUsed>>, next| {
+                let mut profile = profile.unwrap_or_default();
+                profile.extend_from_slice(next);
+                Some(profile)
+            })
+    }
+
+    fn outcomes(&self) -> impl Iterator<Item = &ExecutionOutcomeWithIdView> {
+        std::iter::once(&self.transaction_outcome).chain(&self.receipts_outcome)
+    }
+}
+
+impl fmt::Display for FinalExecutionOutcomeView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Transaction {}: ", self.transaction.hash)?;
+        match &self.status {
+            FinalExecutionStatus::NotStarted => f.write_str("not started")?,
+            FinalExecutionStatus::Started => f.write_str("started")?,
+            FinalExecutionStatus::Failure(err) => write!(f, "failed, cause: {err}")?,
+            FinalExecutionStatus::SuccessValue(_) => f.write_str("succeeded")?,
+        }
+        write!(f, ", receipts: {}", self.receipts_outcome.len())
+    }
+}
+
 impl fmt::Debug for FinalExecutionOutcomeView {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("FinalExecutionOutcome")
@@ -1026,6 +1107,31 @@ pub struct ReceiptView {
     pub receipt: ReceiptEnumView,
 }
 
+impl ReceiptView {
+    /// Returns `(predecessor_id, deposit)` for each transfer action of the receipt
+    pub fn transfers(&self) -> impl Iterator<Item = (&AccountId, Balance)> + '_ {
+        let actions = match &self.receipt {
+            ReceiptEnumView::Action { actions, .. } => actions.as_slice(),
+            ReceiptEnumView::Data { .. } => &[],
+        };
+
+        actions.iter().filter_map(|action| match action {
+            ActionView::Transfer { deposit } => Some((&self.predecessor_id, *deposit)),
+            _ => None,
+        })
+    }
+
+    /// The same as [`transfers`](ReceiptView::transfers()),
+    /// but yields transfers only if the receipt is addressed to the `receiver_id`
+    pub fn incoming_transfers(
+        &self,
+        receiver_id: &AccountId,
+    ) -> impl Iterator<Item = (&AccountId, Balance)> + '_ {
+        let is_incoming = &self.receiver_id == receiver_id;
+        self.transfers().filter(move |_| is_incoming)
+    }
+}
+
 #[derive(
     BorshSerialize,
     BorshDeserialize,
@@ -1056,7 +1162,7 @@ pub struct DataReceiverView {
 pub enum ReceiptEnumView {
     Action {
         signer_id: AccountId,
-        signer_public_key: Ed25519PublicKey,
+        signer_public_key: NearPublicKey,
         #[serde(with = "dec_format")]
         gas_price: Balance,
         output_data_receivers: Vec<DataReceiverView>,
@@ -1146,8 +1252,11 @@ impl TryFrom<ReceiptView> for Receipt {
     }
 }
 
+/// General status of the node
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct StatusResponse {
+    /// Binary version of the node.
+    pub version: Version,
     /// Unique chain id.
     pub chain_id: String,
     /// Currently active protocol version.
@@ -1157,6 +1266,10 @@ pub struct StatusResponse {
     /// Address for RPC server.  None if node doesn’t have RPC endpoint enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rpc_addr: Option<String>,
+    /// Current epoch validators.
+    pub validators: Vec<ValidatorInfo>,
+    /// Sync status of the node.
+    pub sync_info: StatusSyncInfo,
     /// Validator id of the node
     pub validator_account_id: Option<AccountId>,
     /// Public key of the validator.
@@ -1168,3 +1281,140 @@ pub struct StatusResponse {
     /// Uptime of the node.
     pub uptime_sec: i64,
 }
+
+/// Binary version of the node
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub version: String,
+    pub build: String,
+    #[serde(default)]
+    pub rustc_version: String,
+}
+
+/// Validator of the current epoch, that is reported by the node status
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorInfo {
+    pub account_id: AccountId,
+    #[serde(default)]
+    pub is_slashed: bool,
+}
+
+/// Sync status of the node
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StatusSyncInfo {
+    /// Hash of the latest block, that is known to the node
+    pub latest_block_hash: CryptoHash,
+    /// Height of the latest block, that is known to the node
+    pub latest_block_height: BlockHeight,
+    pub latest_state_root: CryptoHash,
+    pub latest_block_time: DateTime<chrono::Utc>,
+    /// Whether the node is catching up with the network
+    pub syncing: bool,
+    pub earliest_block_hash: Option<CryptoHash>,
+    pub earliest_block_height: Option<BlockHeight>,
+    pub earliest_block_time: Option<DateTime<chrono::Utc>>,
+    pub epoch_id: Option<EpochId>,
+    pub epoch_start_height: Option<BlockHeight>,
+}
+
+/// Validators of the current and the next epochs
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct EpochValidatorInfo {
+    /// Validators of the current epoch
+    pub current_validators: Vec<CurrentEpochValidatorInfo>,
+    /// Validators of the next epoch
+    pub next_validators: Vec<NextEpochValidatorInfo>,
+    /// Staking proposals of the current epoch
+    pub current_proposals: Vec<ValidatorStakeView>,
+    /// Height of the first block of the current epoch
+    pub epoch_start_height: BlockHeight,
+}
+
+/// Validator of the current epoch
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct CurrentEpochValidatorInfo {
+    pub account_id: AccountId,
+    pub public_key: NearPublicKey,
+    pub is_slashed: bool,
+    #[serde(with = "dec_format")]
+    pub stake: Balance,
+    pub shards: Vec<ShardId>,
+    pub num_produced_blocks: NumBlocks,
+    pub num_expected_blocks: NumBlocks,
+    #[serde(default)]
+    pub num_produced_chunks: NumBlocks,
+    #[serde(default)]
+    pub num_expected_chunks: NumBlocks,
+}
+
+impl CurrentEpochValidatorInfo {
+    /// Produced and expected blocks of the validator
+    pub fn block_stats(&self) -> ValidatorStats {
+        ValidatorStats {
+            produced: self.num_produced_blocks,
+            expected: self.num_expected_blocks,
+        }
+    }
+
+    /// Produced and expected chunks of the validator
+    pub fn chunk_stats(&self) -> ValidatorStats {
+        ValidatorStats {
+            produced: self.num_produced_chunks,
+            expected: self.num_expected_chunks,
+        }
+    }
+}
+
+/// Validator of the next epoch
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct NextEpochValidatorInfo {
+    pub account_id: AccountId,
+    pub public_key: NearPublicKey,
+    #[serde(with = "dec_format")]
+    pub stake: Balance,
+    pub shards: Vec<ShardId>,
+}
+
+/// Staking proposal of the validator
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ValidatorStakeView {
+    pub account_id: AccountId,
+    pub public_key: NearPublicKey,
+    #[serde(with = "dec_format")]
+    pub stake: Balance,
+}
+
+/// A proof, that the execution outcome is included into the chain
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct LightClientProof {
+    /// The execution outcome with a merkle path to the shard outcome root
+    pub outcome_proof: ExecutionOutcomeWithIdView,
+    /// Merkle path from the shard outcome root to the block outcome root
+    pub outcome_root_proof: MerklePath,
+    /// Header of the block, that contains the outcome
+    pub block_header_lite: LightClientBlockLiteView,
+    /// Merkle path from the block to the block merkle root of the light client head
+    pub block_proof: MerklePath,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct LightClientBlockLiteView {
+    pub prev_block_hash: CryptoHash,
+    pub inner_rest_hash: CryptoHash,
+    pub inner_lite: BlockHeaderInnerLiteView,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct BlockHeaderInnerLiteView {
+    pub height: BlockHeight,
+    pub epoch_id: CryptoHash,
+    pub next_epoch_id: CryptoHash,
+    pub prev_state_root: CryptoHash,
+    pub outcome_root: CryptoHash,
+    /// Legacy json number. Should not be used.
+    pub timestamp: u64,
+    #[serde(with = "dec_format")]
+    pub timestamp_nanosec: u64,
+    pub next_bp_hash: CryptoHash,
+    pub block_merkle_root: CryptoHash,
+}
@@ -0,0 +1,200 @@
+//! ### Non-fungible tokens
+//! ---
+//! Thin wrappers over the [NEP-171](https://nomicon.io/Standards/Tokens/NonFungibleToken/Core)
+//! non-fungible token contract methods, with the [NEP-177](https://nomicon.io/Standards/Tokens/NonFungibleToken/Metadata) metadata
+
+use crate::{
+    client::{FunctionCallBuilder, NearClient, Signer},
+    near_primitives_light::types::Finality,
+    tokens::{ONE_YOCTO, TOKEN_CALL_GAS},
+    Result,
+};
+use near_primitives_core::types::AccountId;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A non-fungible token, see [NEP-171](https://nomicon.io/Standards/Tokens/NonFungibleToken/Core)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    /// The token identifier, unique within the contract
+    pub token_id: String,
+    /// The owner of the token
+    pub owner_id: AccountId,
+    /// The token metadata, if the contract supports NEP-177
+    #[serde(default)]
+    pub metadata: Option<TokenMetadata>,
+    /// Accounts, that are approved to transfer the token, with their approval ids
+    #[serde(default)]
+    pub approved_account_ids: Option<HashMap<AccountId, u64>>,
+}
+
+/// Metadata of a single token, see [NEP-177](https://nomicon.io/Standards/Tokens/NonFungibleToken/Metadata)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    /// Title of the token
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Free-form description of the token
+    #[serde(default)]
+    pub description: Option<String>,
+    /// URL of the associated media
+    #[serde(default)]
+    pub media: Option<String>,
+    /// Base64-encoded sha256 hash of the content from the `media` field
+    #[serde(default)]
+    pub media_hash: Option<String>,
+    /// Number of copies of the token, when it was minted
+    #[serde(default)]
+    pub copies: Option<u64>,
+    /// When the token was issued, unix epoch in milliseconds
+    #[serde(default)]
+    pub issued_at: Option<String>,
+    /// When the token expires, unix epoch in milliseconds
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    /// When the token starts being valid, unix epoch in milliseconds
+    #[serde(default)]
+    pub starts_at: Option<String>,
+    /// When the token was last updated, unix epoch in milliseconds
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Anything extra, that the token wants to store on-chain
+    #[serde(default)]
+    pub extra: Option<String>,
+    /// A link to a JSON file with more information about the token
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// Base64-encoded sha256 hash of the JSON file from the `reference` field
+    #[serde(default)]
+    pub reference_hash: Option<String>,
+}
+
+/// Metadata of a non-fungible token contract, see [NEP-177](https://nomicon.io/Standards/Tokens/NonFungibleToken/Metadata)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NftContractMetadata {
+    /// Version of the metadata standard, like `nft-1.0.0`
+    pub spec: String,
+    /// Human-readable name of the contract
+    pub name: String,
+    /// Abbreviation of the contract name
+    pub symbol: String,
+    /// A small image of the contract, usually a data URL
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Centralized gateway, that is used as a prefix of the token `media` and `reference` links
+    #[serde(default)]
+    pub base_uri: Option<String>,
+    /// A link to a JSON file with more information about the contract
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// Base64-encoded sha256 hash of the JSON file from the `reference` field
+    #[serde(default)]
+    pub reference_hash: Option<String>,
+}
+
+impl NearClient {
+    /// Transfers a non-fungible token with the standard `nft_transfer` call.
+    /// The call is attached with the required 1 yocto Near deposit and 10 Tgas,
+    /// the gas could be changed with the [`FunctionCallBuilder`]
+    ///
+    /// ## Arguments
+    ///
+    /// - `signer` - Transaction [`Signer`], the owner of the token or an approved account
+    /// - `nft_contract` - The [`AccountId`] of the non-fungible token contract
+    /// - `receiver_id` - The account ID of the receiver
+    /// - `token_id` - The token identifier
+    /// - `approval_id` - The approval id, if the signer isn't the owner of the token
+    /// - `memo` - An optional memo, that is logged by the token contract
+    pub fn nft_transfer<'a>(
+        &'a self,
+        signer: &'a Signer,
+        nft_contract: &'a AccountId,
+        receiver_id: &AccountId,
+        token_id: &str,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+    ) -> FunctionCallBuilder<'a> {
+        self.function_call(signer, nft_contract, "nft_transfer")
+            .args(json!({
+                "receiver_id": receiver_id,
+                "token_id": token_id,
+                "approval_id": approval_id,
+                "memo": memo,
+            }))
+            .deposit(ONE_YOCTO)
+            .gas(TOKEN_CALL_GAS)
+    }
+
+    /// Returns the token, or [`None`] if it doesn't exist
+    ///
+    /// ## Arguments
+    ///
+    /// - `nft_contract` - The [`AccountId`] of the non-fungible token contract
+    /// - `token_id` - The token identifier
+    /// - `finality` - [`Finality`]
+    pub async fn nft_token(
+        &self,
+        nft_contract: &AccountId,
+        token_id: &str,
+        finality: Finality,
+    ) -> Result<Option<Token>> {
+        self.view::<Option<Token>>(
+            nft_contract,
+            finality,
+            "nft_token",
+            Some(json!({ "token_id": token_id })),
+        )
+        .await
+        .map(|output| output.data())
+    }
+
+    /// Returns the tokens of the owner, the method is a part of the
+    /// [NEP-181](https://nomicon.io/Standards/Tokens/NonFungibleToken/Enumeration) enumeration
+    ///
+    /// ## Arguments
+    ///
+    /// - `nft_contract` - The [`AccountId`] of the non-fungible token contract
+    /// - `account_id` - The owner of the tokens
+    /// - `from_index` - The index of the first token, the first page is returned if it's [`None`]
+    /// - `limit` - The maximum amount of tokens, the contract limit is used if it's [`None`]
+    /// - `finality` - [`Finality`]
+    pub async fn nft_tokens_for_owner(
+        &self,
+        nft_contract: &AccountId,
+        account_id: &AccountId,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+        finality: Finality,
+    ) -> Result<Vec<Token>> {
+        self.view::<Vec<Token>>(
+            nft_contract,
+            finality,
+            "nft_tokens_for_owner",
+            Some(json!({
+                "account_id": account_id,
+                // The index is u128, that is passed as a string
+                "from_index": from_index.map(|index| index.to_string()),
+                "limit": limit,
+            })),
+        )
+        .await
+        .map(|output| output.data())
+    }
+
+    /// Returns the [`NftContractMetadata`] of the contract
+    ///
+    /// ## Arguments
+    ///
+    /// - `nft_contract` - The [`AccountId`] of the non-fungible token contract
+    /// - `finality` - [`Finality`]
+    pub async fn nft_metadata(
+        &self,
+        nft_contract: &AccountId,
+        finality: Finality,
+    ) -> Result<NftContractMetadata> {
+        self.view::<NftContractMetadata>(nft_contract, finality, "nft_metadata", None)
+            .await
+            .map(|output| output.data())
+    }
+}
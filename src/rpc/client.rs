@@ -1,20 +1,43 @@
 use reqwest::{
-    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
-    Client, ClientBuilder, Response as Resp,
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    Client, ClientBuilder, Response as Resp, StatusCode,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::{Error, NearError};
-use std::borrow::Cow;
+use super::{trace, transport::Transport, Error, NearError};
+use futures::future::{self, Either};
+use futures_timer::Delay;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use url::Url;
 
 type Result<T> = std::result::Result<T, Error>;
 
+/// Max length of the params preview, that is attached to an error
+const PARAMS_PREVIEW_LENGTH: usize = 128;
+/// The method name, that is attached to the errors of a batch request
+const BATCH_METHOD: &str = "batch";
+/// The default version of the JSON-RPC protocol
+const JSONRPC_VERSION: &str = "2.0";
+
 #[derive(Clone)]
 pub(crate) struct RpcClient {
     client: Client,
     url: Url,
+    timeout: Option<Duration>,
+    jsonrpc: Arc<str>,
+    next_id: Arc<AtomicU64>,
+    // The requests are sent over HTTP, if the transport isn't set
+    transport: Option<Arc<dyn Transport>>,
 }
 
 impl RpcClient {
@@ -25,14 +48,90 @@ impl RpcClient {
     ///
     /// - url - It's an RPC endpoint [`Url`]
     pub(crate) fn new(url: Url) -> Result<Self> {
+        Self::from_builder(url, ClientBuilder::new())
+    }
+
+    /// Creates a [`reqwest`] client with the same headers as [`new`](RpcClient::new()),
+    /// each request of the client fails with [`Error::Timeout`] if it takes longer than `timeout`
+    ///
+    /// Arguments
+    ///
+    /// - url - It's an RPC endpoint [`Url`]
+    /// - timeout - Timeout of a single request
+    pub(crate) fn new_with_timeout(url: Url, timeout: Duration) -> Result<Self> {
+        // The fetch based client of wasm doesn't support a client timeout,
+        // so the timeout is applied to each request on all of the targets
+        Ok(Self::new(url)?.with_timeout(timeout))
+    }
+
+    /// Creates a client with the existing [`reqwest`] client,
+    /// so the requests share its connection pool and configuration
+    ///
+    /// Arguments
+    ///
+    /// - client - [`reqwest`] client
+    /// - url - It's an RPC endpoint [`Url`]
+    pub(crate) fn from_parts(client: Client, url: Url) -> Self {
+        Self {
+            client,
+            url,
+            timeout: None,
+            jsonrpc: Arc::from(JSONRPC_VERSION),
+            next_id: Default::default(),
+            transport: None,
+        }
+    }
+
+    /// Creates a client, that sends the requests with the `transport` instead of HTTP
+    ///
+    /// Arguments
+    ///
+    /// - transport - [`Transport`], e.g. the `MockTransport`
+    pub(crate) fn from_transport(transport: impl Transport + 'static) -> Self {
+        Self {
+            transport: Some(Arc::new(transport)),
+            // The HTTP client and the url aren't used by the custom transport
+            ..Self::from_parts(Client::new(), Url::parse("http://localhost").unwrap())
+        }
+    }
+
+    fn from_builder(url: Url, builder: ClientBuilder) -> Result<Self> {
+        validate_url(&url)?;
+
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        let client = ClientBuilder::new()
+        let client = builder
             .default_headers(headers)
             .build()
             .map_err(Error::RpcClientCreate)?;
 
-        Ok(Self { client, url })
+        Ok(Self::from_parts(client, url))
+    }
+
+    /// Creates a client, that shares the connection pool with the current one,
+    /// but applies the `timeout` to each request
+    pub(crate) fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            timeout: Some(timeout),
+            jsonrpc: self.jsonrpc.clone(),
+            next_id: self.next_id.clone(),
+            transport: self.transport.clone(),
+        }
+    }
+
+    /// Creates a client, that shares the connection pool with the current one,
+    /// but sends the `version` in the `jsonrpc` field of the requests
+    pub(crate) fn with_jsonrpc_version(&self, version: &str) -> Self {
+        Self {
+            client: self.client.clone(),
+            url: self.url.clone(),
+            timeout: self.timeout,
+            jsonrpc: Arc::from(version),
+            next_id: self.next_id.clone(),
+            transport: self.transport.clone(),
+        }
     }
 
     /// RPC call to the NEAR network
@@ -45,48 +144,265 @@ impl RpcClient {
     /// Response example:
     /// ```json
     /// {
-    ///   "id": "dontcare",
+    ///   "id": 1,
     ///   "jsonrpc": "2.0",
     ///   "result": "...",
     /// }
     ///
     /// ```
     pub(crate) async fn request(&self, method: &str, params: Option<Value>) -> Result<Value> {
-        let resp = self
-            .client
-            .post(self.url.clone())
-            .json(
-                &serde_json::to_value(&Request::new(method, params))
-                    .map_err(Error::SerializeRpcRequest)?,
+        self.request_with_timeout(method, params, None).await
+    }
+
+    /// The same as [`request`](RpcClient::request()), but overrides the client timeout
+    ///
+    /// Arguments
+    ///
+    /// - method - RPC method
+    /// - params - method arguments, could be empty
+    /// - timeout - request timeout, if it's empty the client timeout is used
+    pub(crate) async fn request_with_timeout(
+        &self,
+        method: &str,
+        params: Option<Value>,
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        let id = self.next_id();
+        trace::request(
+            method,
+            id,
+            self.request_with_id(id, method, params, timeout),
+        )
+        .await
+    }
+
+    async fn request_with_id(
+        &self,
+        id: u64,
+        method: &str,
+        params: Option<Value>,
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        // The transport matches the responses to the requests, so the ids aren't checked
+        if let Some(transport) = &self.transport {
+            return with_timeout(
+                method,
+                timeout.or(self.timeout),
+                transport.request(method, params),
             )
-            .send()
-            .await
-            .and_then(Resp::error_for_status)
-            .map_err(Error::RpcRequest)?;
+            .await;
+        }
+
+        let request = serde_json::to_value(&Request::new(&self.jsonrpc, id, method, params))
+            .map_err(|cause| Error::SerializeRpcRequest {
+                method: method.to_owned(),
+                cause,
+            })?;
 
-        match resp
+        let resp = self
+            .send(method, &request, request.get("params"), timeout)
+            .await?
             .json::<Response>()
             .await
-            .map_err(Error::DeserializeRpcResponse)?
-        {
-            Response {
-                result: RpcResult::Ok(data),
-                ..
-            } => Ok(data),
-            Response {
-                result: RpcResult::Err(err),
-                ..
-            } => Err(err.into()),
+            .map_err(|cause| Error::response(method, cause))?;
+
+        if resp.id != id {
+            return Err(Error::IdMismatch {
+                method: method.to_owned(),
+                expected: id,
+                actual: resp.id,
+            });
+        }
+
+        resp.result.into()
+    }
+
+    /// Sends multiple RPC calls within a single HTTP request
+    ///
+    /// Arguments
+    ///
+    /// - calls - RPC methods with their arguments
+    ///
+    /// Return
+    ///
+    /// Results are in the same order as the calls.
+    /// If the HTTP request fails, an error is returned for the whole batch
+    pub(crate) async fn request_batch(
+        &self,
+        calls: Vec<(&str, Option<Value>)>,
+    ) -> Result<Vec<Result<Value>>> {
+        if calls.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // A custom transport receives the calls one by one,
+        // the whole batch is bounded by the client timeout, like a single HTTP request
+        if let Some(transport) = &self.transport {
+            let batch = async {
+                let mut results = Vec::with_capacity(calls.len());
+                for (method, params) in calls {
+                    results.push(transport.request(method, params).await);
+                }
+                Ok(results)
+            };
+            return with_timeout(BATCH_METHOD, self.timeout, batch).await;
+        }
+
+        let requests = calls
+            .into_iter()
+            .map(|(method, params)| Request::new(&self.jsonrpc, self.next_id(), method, params))
+            .collect::<Vec<_>>();
+        let body = serde_json::to_value(&requests).map_err(|cause| Error::SerializeRpcRequest {
+            method: BATCH_METHOD.to_owned(),
+            cause,
+        })?;
+
+        let batch = async {
+            self.send(BATCH_METHOD, &body, None, None)
+                .await?
+                .json::<Vec<Response>>()
+                .await
+                .map_err(|cause| Error::response(BATCH_METHOD, cause))
+        };
+        // The batch is traced with the id of its first request
+        let mut responses = trace::request(BATCH_METHOD, requests[0].id, batch)
+            .await?
+            .into_iter()
+            .map(|resp| (resp.id, resp.result))
+            .collect::<HashMap<_, _>>();
+
+        Ok(requests
+            .iter()
+            .map(|request| match responses.remove(&request.id) {
+                Some(result) => result.into(),
+                None => Err(Error::MissingBatchResponse {
+                    method: request.method.to_string(),
+                }),
+            })
+            .collect())
+    }
+
+    /// Sends a JSON POST request to a service, that isn't the RPC endpoint,
+    /// but shares the client connection pool and timeout
+    ///
+    /// Arguments
+    ///
+    /// - method - The name of the request, that is attached to the errors
+    /// - url - The service [`Url`]
+    /// - body - The request body
+    #[cfg(feature = "testnet")]
+    pub(crate) async fn post(&self, method: &str, url: &Url, body: &Value) -> Result<()> {
+        self.send_to(url, method, body, None, None)
+            .await
+            .map(|_| ())
+    }
+
+    async fn send(
+        &self,
+        method: &str,
+        body: &Value,
+        params: Option<&Value>,
+        timeout: Option<Duration>,
+    ) -> Result<Resp> {
+        self.send_to(&self.url, method, body, params, timeout).await
+    }
+
+    async fn send_to(
+        &self,
+        url: &Url,
+        method: &str,
+        body: &Value,
+        params: Option<&Value>,
+        timeout: Option<Duration>,
+    ) -> Result<Resp> {
+        let mut builder = self.client.post(url.clone()).json(body);
+        if let Some(timeout) = timeout.or(self.timeout) {
+            builder = builder.timeout(timeout);
+        }
+
+        let resp = builder
+            .send()
+            .await
+            .map_err(|cause| Error::request(method, params_preview(params), cause))?;
+
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(resp);
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited {
+                method: method.to_owned(),
+                retry_after: retry_after(resp.headers()),
+            });
         }
+
+        // The body of an error response often contains the cause from the provider
+        let body = resp
+            .text()
+            .await
+            .map_err(|cause| Error::response(method, cause))?;
+
+        Err(Error::Http {
+            method: method.to_owned(),
+            status: status.as_u16(),
+            body,
+        })
+    }
+
+    fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Rejects the endpoints, that obviously can't receive the requests,
+/// so the error is returned on the client creation instead of the first request
+fn validate_url(url: &Url) -> Result<()> {
+    let cause = if !matches!(url.scheme(), "http" | "https") {
+        "the scheme isn't http or https"
+    } else if url.host_str().map_or(true, str::is_empty) {
+        "the host is missing"
+    } else {
+        return Ok(());
+    };
+
+    Err(Error::InvalidUrl {
+        url: url.to_string(),
+        cause,
+    })
+}
+
+/// Parses the `Retry-After` header, only the delay in seconds is supported, but not the HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Truncated string representation of the request params
+fn params_preview(params: Option<&Value>) -> String {
+    let Some(params) = params.filter(|params| !params.is_null()) else {
+        return String::new();
+    };
+
+    let params = params.to_string();
+    match params.char_indices().nth(PARAMS_PREVIEW_LENGTH) {
+        Some((idx, _)) => format!("{}...", &params[..idx]),
+        None => params,
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Request<'a> {
     /// JSON-RPC version.
-    pub jsonrpc: &'static str,
-    /// Request ID
-    pub id: &'static str,
+    pub jsonrpc: &'a str,
+    /// Request ID, it's unique for the client
+    pub id: u64,
     /// Name of the method to be invoked.
     #[serde(borrow)]
     pub method: Cow<'a, str>,
@@ -95,10 +411,10 @@ struct Request<'a> {
 }
 
 impl<'a> Request<'a> {
-    fn new(method: &'a str, params: Option<Value>) -> Self {
+    fn new(jsonrpc: &'a str, id: u64, method: &'a str, params: Option<Value>) -> Self {
         Self {
-            jsonrpc: "2.0",
-            id: "dontcare",
+            jsonrpc,
+            id,
             method: Cow::from(method),
             params,
         }
@@ -113,7 +429,7 @@ struct Response {
     #[serde(flatten)]
     pub result: RpcResult,
     /// Request ID
-    pub id: String,
+    pub id: u64,
 }
 
 /// Near result format
@@ -125,6 +441,35 @@ enum RpcResult {
     Err(NearError),
 }
 
+impl From<RpcResult> for Result<Value> {
+    fn from(result: RpcResult) -> Self {
+        match result {
+            RpcResult::Ok(data) => Ok(data),
+            RpcResult::Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Bounds a request of a custom transport with the `timeout`, if it's set
+async fn with_timeout<T>(
+    method: &str,
+    timeout: Option<Duration>,
+    request: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let Some(timeout) = timeout else {
+        return request.await;
+    };
+
+    futures::pin_mut!(request);
+    match future::select(request, Delay::new(timeout)).await {
+        Either::Left((output, _)) => output,
+        Either::Right(_) => Err(Error::TransportTimeout {
+            method: method.to_owned(),
+            timeout,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -135,17 +480,192 @@ mod tests {
         let resp = Response {
             jsonrpc: "2.0".to_owned(),
             result: RpcResult::Ok(Value::String("some value".to_owned())),
-            id: "dontcare".to_owned(),
+            id: 1,
         };
 
         assert_eq!(
             serde_json::to_value(resp).unwrap(),
             serde_json::to_value(serde_json::json!({
-                "id": "dontcare",
+                "id": 1,
                 "jsonrpc": "2.0",
                 "result": "some value",
             }))
             .unwrap()
         );
     }
+
+    #[test]
+    fn unique_request_ids() {
+        let client = RpcClient::new("http://localhost:3030".parse().unwrap()).unwrap();
+        let with_timeout = client.with_timeout(Duration::from_secs(1));
+
+        let ids = [client.next_id(), with_timeout.next_id(), client.next_id()];
+        assert_eq!(ids, [0, 1, 2]);
+    }
+
+    #[test]
+    fn request_timeout() {
+        let url: Url = "http://localhost:3030".parse().unwrap();
+        assert_eq!(RpcClient::new(url.clone()).unwrap().timeout, None);
+
+        let timeout = Duration::from_secs(5);
+        let client = RpcClient::new_with_timeout(url, timeout).unwrap();
+        assert_eq!(client.timeout, Some(timeout));
+    }
+
+    // Responds to a single request with the raw HTTP response
+    async fn serve_once(response: &'static str) -> Url {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 4096];
+            let _ = stream.read(&mut buf).await.unwrap();
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        });
+
+        url.parse().unwrap()
+    }
+
+    #[test]
+    fn invalid_url() {
+        for url in [
+            "ws://localhost:3030",
+            "unix:/run/near.sock",
+            "data:text/plain,rpc",
+        ] {
+            assert!(matches!(
+                RpcClient::new(url.parse().unwrap()),
+                Err(Error::InvalidUrl { .. })
+            ));
+        }
+
+        assert!(RpcClient::new("https://rpc.testnet.near.org/api".parse().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn jsonrpc_version() {
+        let client = RpcClient::new("http://localhost:3030".parse().unwrap())
+            .unwrap()
+            .with_jsonrpc_version("1.0");
+        let request =
+            serde_json::to_value(Request::new(&client.jsonrpc, 0, "status", None)).unwrap();
+
+        assert_eq!(request["jsonrpc"], "1.0");
+    }
+
+    #[tokio::test]
+    async fn http_error_with_body() {
+        let url = serve_once(
+            "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 15\r\nconnection: close\r\n\r\nnode is syncing",
+        )
+        .await;
+        let client = RpcClient::new(url).unwrap();
+
+        let err = client.request("status", None).await.unwrap_err();
+        assert!(matches!(
+            &err,
+            Error::Http { method, status: 503, body } if method == "status" && body == "node is syncing"
+        ));
+        assert!(err.is_transient());
+
+        let url = serve_once(
+            "HTTP/1.1 400 Bad Request\r\ncontent-length: 11\r\nconnection: close\r\n\r\nbad request",
+        )
+        .await;
+        let err = RpcClient::new(url)
+            .unwrap()
+            .request("status", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Http { status: 400, .. }));
+        assert!(!err.is_transient());
+    }
+
+    #[tokio::test]
+    async fn rate_limited() {
+        let url = serve_once(
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 2\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+        )
+        .await;
+        let err = RpcClient::new(url)
+            .unwrap()
+            .request("status", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::RateLimited { .. }));
+        assert!(err.is_transient());
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(2)));
+
+        let url = serve_once(
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: Wed, 21 Oct 2015 07:28:00 GMT\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+        )
+        .await;
+        let err = RpcClient::new(url)
+            .unwrap()
+            .request("status", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: None,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn transport_timeout() {
+        // Never responds
+        struct PendingTransport;
+
+        impl Transport for PendingTransport {
+            fn request<'a>(
+                &'a self,
+                _: &'a str,
+                _: Option<Value>,
+            ) -> futures::future::BoxFuture<'a, Result<Value>> {
+                Box::pin(future::pending())
+            }
+        }
+
+        let client = RpcClient::from_transport(PendingTransport);
+        let timeout = Duration::from_millis(50);
+
+        assert!(matches!(
+            client
+                .request_with_timeout("status", None, Some(timeout))
+                .await,
+            Err(Error::TransportTimeout { method, .. }) if method == "status"
+        ));
+        assert!(matches!(
+            client
+                .with_timeout(timeout)
+                .request_batch(vec![("status", None), ("gas_price", None)])
+                .await,
+            Err(Error::TransportTimeout { method, .. }) if method == BATCH_METHOD
+        ));
+    }
+
+    #[test]
+    fn params_preview_truncated() {
+        assert_eq!(params_preview(None), "");
+        assert_eq!(
+            params_preview(Some(&serde_json::json!(["abc"]))),
+            "[\"abc\"]"
+        );
+
+        let long = serde_json::json!(["a".repeat(PARAMS_PREVIEW_LENGTH * 2)]);
+        let preview = params_preview(Some(&long));
+        assert!(preview.ends_with("..."));
+        assert_eq!(preview.chars().count(), PARAMS_PREVIEW_LENGTH + 3);
+    }
 }
@@ -1,21 +1,249 @@
 pub(crate) mod client;
+mod trace;
+pub mod transport;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 
+/// Errors of the RPC requests, that are returned by a [`Transport`](transport::Transport)
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// The HTTP client couldn't be created
     #[error("Couldn't create a RpcClient: [\"{0}\"]")]
     RpcClientCreate(reqwest::Error),
-    #[error("Rpc request failed with: [\"{0}\"]")]
-    RpcRequest(reqwest::Error),
-    #[error("Failed to serialize an RPC request: [\"{0}\"]")]
-    SerializeRpcRequest(serde_json::Error),
-    #[error("Failed to deserialize an RPC response: [\"{0}\"]")]
-    DeserializeRpcResponse(reqwest::Error),
+    /// The RPC endpoint isn't a valid HTTP(S) url
+    #[error("Invalid RPC endpoint `{url}`: [\"{cause}\"]")]
+    InvalidUrl {
+        /// The endpoint url
+        url: String,
+        /// Why the url is rejected
+        cause: &'static str,
+    },
+    /// The request failed for another reason, than the ones below
+    #[error("Rpc `{method}` request failed with: [\"{cause}\"], params: [{params}]")]
+    RpcRequest {
+        /// RPC method
+        method: String,
+        /// A preview of the request params
+        params: String,
+        /// The HTTP client error
+        cause: reqwest::Error,
+    },
+    /// The request params couldn't be serialized
+    #[error("Failed to serialize an RPC `{method}` request: [\"{cause}\"]")]
+    SerializeRpcRequest {
+        /// RPC method
+        method: String,
+        /// The serialization error
+        cause: serde_json::Error,
+    },
+    /// The endpoint isn't reachable
+    #[error("Rpc `{method}` couldn't connect to the endpoint: [\"{cause}\"]")]
+    Connect {
+        /// RPC method
+        method: String,
+        /// The HTTP client error
+        cause: reqwest::Error,
+    },
+    /// The request isn't answered in time
+    #[error("Rpc `{method}` request timed out: [\"{cause}\"]")]
+    Timeout {
+        /// RPC method
+        method: String,
+        /// The HTTP client error
+        cause: reqwest::Error,
+    },
+    /// A request, that is sent with a custom [`Transport`](transport::Transport), isn't answered in time
+    #[error("Rpc `{method}` request isn't answered by the transport in [{timeout:?}]")]
+    TransportTimeout {
+        /// RPC method
+        method: String,
+        /// The request timeout
+        timeout: Duration,
+    },
+    /// The TLS connection couldn't be established
+    #[error("Rpc `{method}` failed to establish a TLS connection: [\"{cause}\"]")]
+    Tls {
+        /// RPC method
+        method: String,
+        /// The HTTP client error
+        cause: reqwest::Error,
+    },
+    /// The response body isn't a valid JSON-RPC response
+    #[error("Failed to decode an RPC `{method}` response: [\"{cause}\"]")]
+    Decode {
+        /// RPC method
+        method: String,
+        /// The HTTP client error
+        cause: reqwest::Error,
+    },
+    /// The endpoint responded with an unsuccessful HTTP status
+    #[error("Rpc `{method}` responded with the HTTP status [{status}], body: [\"{body}\"]")]
+    Http {
+        /// RPC method
+        method: String,
+        /// HTTP status code
+        status: u16,
+        /// Response body
+        body: String,
+    },
+    /// The endpoint responded with the `429 Too Many Requests` status
+    #[error("Rpc `{method}` request is rate limited, retry after: [{retry_after:?}]")]
+    RateLimited {
+        /// RPC method
+        method: String,
+        /// The delay from the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+    /// The node responded with an error
     #[error("Near protocol error: [\"{0}\"]")]
     NearProtocol(NearError),
+    /// A batch response doesn't contain a response of the request
+    #[error("Rpc `{method}` response is missing in the batch response")]
+    MissingBatchResponse {
+        /// RPC method
+        method: String,
+    },
+    /// A response id differs from the request id
+    #[error("Rpc `{method}` response id [{actual}] doesn't match the request id [{expected}]")]
+    IdMismatch {
+        /// RPC method
+        method: String,
+        /// The request id
+        expected: u64,
+        /// The response id
+        actual: u64,
+    },
+}
+
+impl Error {
+    /// Classifies an error that happened during sending a request
+    pub(crate) fn request(method: &str, params: String, cause: reqwest::Error) -> Self {
+        let method = method.to_owned();
+
+        if cause.is_timeout() {
+            return Self::Timeout { method, cause };
+        }
+
+        // The fetch API of wasm doesn't expose the connection errors
+        #[cfg(not(target_arch = "wasm32"))]
+        if cause.is_connect() {
+            return if is_tls(&cause) {
+                Self::Tls { method, cause }
+            } else {
+                Self::Connect { method, cause }
+            };
+        }
+
+        if cause.is_decode() {
+            Self::Decode { method, cause }
+        } else {
+            Self::RpcRequest {
+                method,
+                params,
+                cause,
+            }
+        }
+    }
+
+    /// Whether the request could succeed if it's sent one more time,
+    /// like a timeout, a failed connection or a 5xx response
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Self::Timeout { .. }
+            | Self::TransportTimeout { .. }
+            | Self::Connect { .. }
+            | Self::RpcRequest { .. }
+            | Self::RateLimited { .. } => true,
+            Self::Http { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// The name of the error variant, that is recorded by the traces
+    #[cfg(feature = "tracing")]
+    pub(crate) const fn kind(&self) -> &'static str {
+        match self {
+            Self::RpcClientCreate(_) => "rpc_client_create",
+            Self::InvalidUrl { .. } => "invalid_url",
+            Self::RpcRequest { .. } => "rpc_request",
+            Self::SerializeRpcRequest { .. } => "serialize_rpc_request",
+            Self::Connect { .. } => "connect",
+            Self::Timeout { .. } => "timeout",
+            Self::TransportTimeout { .. } => "transport_timeout",
+            Self::Tls { .. } => "tls",
+            Self::Decode { .. } => "decode",
+            Self::Http { .. } => "http",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::NearProtocol(_) => "near_protocol",
+            Self::MissingBatchResponse { .. } => "missing_batch_response",
+            Self::IdMismatch { .. } => "id_mismatch",
+        }
+    }
+
+    /// Whether a node doesn't know a transaction yet, or it's still being processed,
+    /// so its status could be queried one more time
+    pub(crate) fn is_unknown_transaction(&self) -> bool {
+        matches!(
+            self,
+            Self::NearProtocol(NearError {
+                error: NearErrorVariant::Handler(
+                    CauseKind::UnknownTransaction(_) | CauseKind::TimeoutError
+                ),
+                ..
+            })
+        )
+    }
+
+    /// Whether a receipt isn't executed yet, or a block with its outcome isn't final,
+    /// so its outcome could be queried one more time
+    pub(crate) fn is_unknown_receipt(&self) -> bool {
+        matches!(
+            self,
+            Self::NearProtocol(NearError {
+                error: NearErrorVariant::Handler(
+                    CauseKind::UnknownTransactionOrReceipt(_) | CauseKind::NotConfirmed(_)
+                ),
+                ..
+            })
+        )
+    }
+
+    /// The delay, that is requested by the provider with the `Retry-After` header
+    pub(crate) fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Classifies an error that happened during reading a response body
+    pub(crate) fn response(method: &str, cause: reqwest::Error) -> Self {
+        let method = method.to_owned();
+
+        if cause.is_timeout() {
+            Self::Timeout { method, cause }
+        } else {
+            Self::Decode { method, cause }
+        }
+    }
+}
+
+// reqwest doesn't expose the TLS errors, so the cause chain is inspected
+#[cfg(not(target_arch = "wasm32"))]
+fn is_tls(err: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(err);
+
+    while let Some(err) = source {
+        let cause = err.to_string().to_lowercase();
+        if cause.contains("tls") || cause.contains("certificate") || cause.contains("handshake") {
+            return true;
+        }
+        source = err.source();
+    }
+
+    false
 }
 
 impl From<NearError> for Error {
@@ -24,6 +252,7 @@ impl From<NearError> for Error {
     }
 }
 
+/// An error, that is returned by a Near node, it's deserialized from the JSON-RPC `error` field
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NearError {
     #[serde(flatten)]
@@ -32,43 +261,53 @@ pub struct NearError {
     message: Option<String>,
 }
 
+/// A kind of the [`NearError`]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "name", content = "cause")]
 pub enum NearErrorVariant {
+    /// The request is malformed
     #[serde(rename = "REQUEST_VALIDATION_ERROR")]
     RequestValidation(CauseKind),
+    /// The request couldn't be handled
     #[serde(rename = "HANDLER_ERROR")]
     Handler(CauseKind),
+    /// The node failed
     #[serde(rename = "INTERNAL_ERROR")]
     Internal(CauseKind),
 }
 
+/// A cause of the [`NearError`]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "name", content = "info", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CauseKind {
+    /// The transaction is rejected or failed
     InvalidTransaction(Value),
+    /// The transaction isn't known to the node
+    UnknownTransaction(Value),
+    /// The transaction or the receipt isn't known to the node
+    UnknownTransactionOrReceipt(Value),
+    /// The block with the outcome isn't final yet
+    NotConfirmed(Value),
+    /// The transaction isn't finished in time
     TimeoutError,
+    /// The request params couldn't be parsed
     ParseError(Value),
+    /// The node failed
     InternalError(Value),
 }
 
 impl NearError {
-    pub fn handler(cause: Value) -> Self {
-        Self {
-            error: NearErrorVariant::Handler(CauseKind::InvalidTransaction(cause)),
-            data: None,
-            message: None,
-        }
-    }
-
+    /// Additional data of the error
     pub fn data(&self) -> Option<&Value> {
         self.data.as_ref()
     }
 
+    /// Human readable message of the error
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
 
+    /// Kind and cause of the error
     pub fn error(&self) -> &NearErrorVariant {
         &self.error
     }
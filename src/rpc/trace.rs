@@ -0,0 +1,45 @@
+//! Tracing of the RPC requests, it's enabled with the `tracing` feature.
+//! Without the feature a request is just awaited
+
+use super::Error;
+use std::future::Future;
+
+/// Awaits the request inside of a span, that records the RPC method, the request id,
+/// the latency and the kind of the error, if the request fails
+#[cfg(feature = "tracing")]
+pub(crate) async fn request<T>(
+    method: &str,
+    id: u64,
+    request: impl Future<Output = Result<T, Error>>,
+) -> Result<T, Error> {
+    use tracing::{field::Empty, Instrument};
+
+    let span = tracing::debug_span!("rpc", method, id, latency_ms = Empty, error = Empty);
+    // std::time::Instant isn't supported by the browser
+    #[cfg(not(target_arch = "wasm32"))]
+    let start = std::time::Instant::now();
+
+    let res = request.instrument(span.clone()).await;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+
+    match &res {
+        Ok(_) => tracing::debug!(parent: &span, "rpc request succeeded"),
+        Err(err) => {
+            span.record("error", err.kind());
+            tracing::warn!(parent: &span, error = %err, "rpc request failed");
+        }
+    }
+
+    res
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) async fn request<T>(
+    _method: &str,
+    _id: u64,
+    request: impl Future<Output = Result<T, Error>>,
+) -> Result<T, Error> {
+    request.await
+}
@@ -0,0 +1,109 @@
+//! ### RPC transport
+//! ---
+//! The RPC requests are sent over HTTP by default,
+//! but the [`NearClient`](crate::client::NearClient) could be created with another [`Transport`],
+//! e.g. the `MockTransport` with canned responses, that is enabled with the `mock` feature,
+//! so the client logic is tested without a sandbox
+
+pub use super::{CauseKind, Error, NearError, NearErrorVariant};
+use futures::future::BoxFuture;
+use serde_json::Value;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Sends an RPC call and returns its result.
+///
+/// A request is bounded by the client timeout, like an HTTP request,
+/// and fails with [`Error::TransportTimeout`], if it isn't answered in time.
+/// The client doesn't check the JSON-RPC ids of the results, that are returned by a transport,
+/// so matching a response to its request is the transport's responsibility.
+/// The calls of a batch request are sent to the transport one by one
+pub trait Transport: Send + Sync {
+    /// Arguments
+    ///
+    /// - method - RPC method
+    /// - params - method arguments, could be empty
+    fn request<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Value>,
+    ) -> BoxFuture<'a, Result<Value>>;
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn request<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Value>,
+    ) -> BoxFuture<'a, Result<Value>> {
+        (**self).request(method, params)
+    }
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub use mock::MockTransport;
+
+#[cfg(any(test, feature = "mock"))]
+mod mock {
+    use super::{Result, Transport};
+    use futures::future::{self, BoxFuture, FutureExt};
+    use serde_json::Value;
+    use std::sync::Mutex;
+
+    /// Responds to the RPC calls with canned responses.
+    /// Each response is returned once, to the first matching call, in order of adding
+    #[derive(Default)]
+    pub struct MockTransport {
+        responses: Mutex<Vec<(String, Option<Value>, Result<Value>)>>,
+        requests: Mutex<Vec<(String, Option<Value>)>>,
+    }
+
+    impl MockTransport {
+        /// Adds a response to the `method` call, that matches any params
+        pub fn respond(self, method: &str, response: Result<Value>) -> Self {
+            self.push(method, None, response)
+        }
+
+        /// Adds a response to the `method` call with exactly the same `params`
+        pub fn respond_to(self, method: &str, params: Value, response: Result<Value>) -> Self {
+            self.push(method, Some(params), response)
+        }
+
+        /// The calls, that are received by the transport, in order of receiving
+        pub fn requests(&self) -> Vec<(String, Option<Value>)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn push(self, method: &str, params: Option<Value>, response: Result<Value>) -> Self {
+            self.responses
+                .lock()
+                .unwrap()
+                .push((method.to_owned(), params, response));
+            self
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn request<'a>(
+            &'a self,
+            method: &'a str,
+            params: Option<Value>,
+        ) -> BoxFuture<'a, Result<Value>> {
+            let mut responses = self.responses.lock().unwrap();
+            let response = responses
+                .iter()
+                .position(|(expected_method, expected_params, _)| {
+                    expected_method == method
+                        && (expected_params.is_none() || *expected_params == params)
+                })
+                .map(|idx| responses.remove(idx).2)
+                .unwrap_or_else(|| panic!("Unexpected RPC call `{method}`, params: {params:?}"));
+
+            self.requests
+                .lock()
+                .unwrap()
+                .push((method.to_owned(), params));
+            future::ready(response).boxed()
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! ### Testnet
+//! ---
+//! Helpers, that work only with the Near testnet, they are enabled with the `testnet` feature
+
+use crate::{client::NearClient, crypto::prelude::*, Error, Result};
+use near_primitives_core::types::AccountId;
+use serde_json::json;
+use url::Url;
+
+/// The testnet helper service, that creates the accounts funded by the faucet
+const TESTNET_HELPER_URL: &str = "https://helper.testnet.near.org/account";
+
+impl NearClient {
+    /// Creates a new account, that is funded by the testnet faucet.
+    /// The account could be used right away with a [`Signer`](crate::client::Signer)
+    /// of the secret key, that corresponds to the `new_pk`
+    ///
+    /// ## Arguments
+    ///
+    /// - `new_account_id` - The new account ID, like `some-name.testnet`
+    /// - `new_pk` - The full access [`NearPublicKey`] of the new account
+    ///
+    /// ## Errors
+    ///
+    /// [`Error::Faucet`], if the helper service rejects the request,
+    /// for example when the account already exists or the faucet is rate limited
+    pub async fn request_faucet_funds(
+        &self,
+        new_account_id: &AccountId,
+        new_pk: impl Into<NearPublicKey>,
+    ) -> Result<()> {
+        let url = Url::parse(TESTNET_HELPER_URL).expect("The testnet helper url is valid");
+        let body = json!({
+            "newAccountId": new_account_id,
+            "newAccountPublicKey": new_pk.into(),
+        });
+
+        self.rpc_client
+            .post("create_account", &url, &body)
+            .await
+            .map_err(Error::Faucet)
+    }
+}
@@ -0,0 +1,158 @@
+//! ### Fungible tokens
+//! ---
+//! Thin wrappers over the [NEP-141](https://nomicon.io/Standards/Tokens/FungibleToken/Core)
+//! fungible token contract methods, with the standard method names and arguments
+
+use crate::{
+    client::{FunctionCallBuilder, NearClient, Signer},
+    near_primitives_light::types::Finality,
+    Result,
+};
+use near_primitives_core::{
+    serialize::dec_format,
+    types::{AccountId, Balance, Gas},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// The deposit, that is required by the token standards to confirm a call with a full access key
+pub(crate) const ONE_YOCTO: Balance = 1;
+
+/// Default gas of the token calls, 10 Tgas
+pub(crate) const TOKEN_CALL_GAS: Gas = 10_000_000_000_000;
+
+/// Metadata of a fungible token, see [NEP-148](https://nomicon.io/Standards/Tokens/FungibleToken/Metadata)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FtMetadata {
+    /// Version of the metadata standard, like `ft-1.0.0`
+    pub spec: String,
+    /// Human-readable name of the token
+    pub name: String,
+    /// Abbreviation of the token name
+    pub symbol: String,
+    /// A small image of the token, usually a data URL
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// A link to a JSON file with more information about the token
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// Base64-encoded sha256 hash of the JSON file from the `reference` field
+    #[serde(default)]
+    pub reference_hash: Option<String>,
+    /// Number of decimals of the token amounts
+    pub decimals: u8,
+}
+
+/// NEP-141 amounts are passed as strings, as JSON can't hold u128
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct U128(#[serde(with = "dec_format")] Balance);
+
+impl NearClient {
+    /// Transfers fungible tokens with the standard `ft_transfer` call.
+    /// The call is attached with the required 1 yocto Near deposit and 10 Tgas,
+    /// the gas could be changed with the [`FunctionCallBuilder`]
+    ///
+    /// ## Arguments
+    ///
+    /// - `signer` - Transaction [`Signer`], the owner of the tokens
+    /// - `token_contract` - The [`AccountId`] of the fungible token contract
+    /// - `receiver_id` - The account ID of the receiver, it has to be registered in the token contract
+    /// - `amount` - The amount of tokens in the smallest units of the token
+    /// - `memo` - An optional memo, that is logged by the token contract
+    pub fn ft_transfer<'a>(
+        &'a self,
+        signer: &'a Signer,
+        token_contract: &'a AccountId,
+        receiver_id: &AccountId,
+        amount: Balance,
+        memo: Option<String>,
+    ) -> FunctionCallBuilder<'a> {
+        self.function_call(signer, token_contract, "ft_transfer")
+            .args(json!({
+                "receiver_id": receiver_id,
+                "amount": amount.to_string(),
+                "memo": memo,
+            }))
+            .deposit(ONE_YOCTO)
+            .gas(TOKEN_CALL_GAS)
+    }
+
+    /// Registers an account in the token contract with the standard
+    /// [storage management](https://nomicon.io/Standards/StorageManagement) `storage_deposit` call,
+    /// an account has to be registered before it receives tokens
+    ///
+    /// ## Arguments
+    ///
+    /// - `signer` - Transaction [`Signer`], that pays for the storage
+    /// - `token_contract` - The [`AccountId`] of the fungible token contract
+    /// - `account_id` - The account ID to register, the signer account is registered if it's [`None`]
+    /// - `amount` - The storage deposit, see `storage_balance_bounds` of the token contract
+    pub fn storage_deposit<'a>(
+        &'a self,
+        signer: &'a Signer,
+        token_contract: &'a AccountId,
+        account_id: Option<&AccountId>,
+        amount: Balance,
+    ) -> FunctionCallBuilder<'a> {
+        self.function_call(signer, token_contract, "storage_deposit")
+            .args(json!({ "account_id": account_id }))
+            .deposit(amount)
+            .gas(TOKEN_CALL_GAS)
+    }
+
+    /// Returns the token balance of the account
+    ///
+    /// ## Arguments
+    ///
+    /// - `token_contract` - The [`AccountId`] of the fungible token contract
+    /// - `account_id` - The account ID, whose balance is requested
+    /// - `finality` - [`Finality`]
+    pub async fn ft_balance_of(
+        &self,
+        token_contract: &AccountId,
+        account_id: &AccountId,
+        finality: Finality,
+    ) -> Result<Balance> {
+        self.view::<U128>(
+            token_contract,
+            finality,
+            "ft_balance_of",
+            Some(json!({ "account_id": account_id })),
+        )
+        .await
+        .map(|output| output.data().0)
+    }
+
+    /// Returns the total supply of the token
+    ///
+    /// ## Arguments
+    ///
+    /// - `token_contract` - The [`AccountId`] of the fungible token contract
+    /// - `finality` - [`Finality`]
+    pub async fn ft_total_supply(
+        &self,
+        token_contract: &AccountId,
+        finality: Finality,
+    ) -> Result<Balance> {
+        self.view::<U128>(token_contract, finality, "ft_total_supply", None)
+            .await
+            .map(|output| output.data().0)
+    }
+
+    /// Returns the [`FtMetadata`] of the token
+    ///
+    /// ## Arguments
+    ///
+    /// - `token_contract` - The [`AccountId`] of the fungible token contract
+    /// - `finality` - [`Finality`]
+    pub async fn ft_metadata(
+        &self,
+        token_contract: &AccountId,
+        finality: Finality,
+    ) -> Result<FtMetadata> {
+        self.view::<FtMetadata>(token_contract, finality, "ft_metadata", None)
+            .await
+            .map(|output| output.data())
+    }
+}
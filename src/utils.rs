@@ -15,7 +15,7 @@ use crate::{
     Error, Result,
 };
 use near_primitives_core::{
-    hash::CryptoHash,
+    hash::{hash, CryptoHash},
     types::{BlockHeight, Nonce},
 };
 use serde::{
@@ -25,33 +25,34 @@ use serde::{
 use serde_json::Value;
 use std::fmt;
 
-pub(crate) fn extract_logs(
-    logs: impl IntoIterator<Item = ExecutionOutcomeWithIdView>,
+/// Collects logs from all of the execution outcomes, preserving their order
+pub(crate) fn extract_logs<'a>(
+    outcomes: impl IntoIterator<Item = &'a ExecutionOutcomeWithIdView>,
 ) -> Vec<String> {
-    logs.into_iter()
-        .find_map(|it| {
-            if it.outcome.logs.is_empty() {
-                None
-            } else {
-                Some(it.outcome.logs)
-            }
-        })
-        .unwrap_or_default()
+    outcomes
+        .into_iter()
+        .flat_map(|it| it.outcome.logs.iter().cloned())
+        .collect()
 }
 
 /// Serialize and sign a transaction
-/// During call it requests the most recent block [`CryptoHash`]
+/// If the block [`CryptoHash`] isn't provided, requests the most recent one
 pub(crate) async fn serialize_transaction<'a>(
     info: &'a TransactionInfo<'_>,
     actions: Vec<Action>,
+    nonce: Nonce,
+    block_hash: Option<CryptoHash>,
     block_finality: Finality,
 ) -> Result<Vec<u8>> {
-    let block_hash = info.client().block(block_finality).await?;
+    let block_hash = match block_hash {
+        Some(block_hash) => block_hash,
+        None => info.client().block(block_finality).await?,
+    };
 
     let transaction = Transaction {
         signer_id: info.signer().account().clone(),
-        public_key: *info.signer().public_key(),
-        nonce: info.signer().nonce() + 1,
+        public_key: info.signer().public_key().into(),
+        nonce,
         receiver_id: info.contract().clone(),
         block_hash,
         actions,
@@ -61,6 +62,16 @@ pub(crate) async fn serialize_transaction<'a>(
     borsh::to_vec(&signed_transaction).map_err(Error::TxSerialization)
 }
 
+/// Computes a hash of the contract code, the same way as the protocol does.
+/// Could be compared with a hash returned by [`view_contract_code`](crate::client::NearClient::view_contract_code())
+///
+/// ## Arguments
+///
+/// - **wasm** - Compiled contract code
+pub fn code_hash(wasm: &[u8]) -> CryptoHash {
+    hash(wasm)
+}
+
 #[allow(clippy::result_large_err)]
 pub(crate) fn serialize_arguments(args: Option<Value>) -> Result<Vec<u8>> {
     Ok(args
@@ -73,7 +84,7 @@ pub(crate) fn serialize_arguments(args: Option<Value>) -> Result<Vec<u8>> {
 
 pub(crate) fn sign_transaction(signer: &Signer, transaction: Transaction) -> SignedTransaction {
     let (hash, ..) = transaction.get_hash_and_size();
-    let signature = signer.sign(hash.0.as_ref());
+    let signature = signer.sign(&hash);
     SignedTransaction::new(signature, transaction)
 }
 
@@ -211,6 +211,63 @@ fn public_key_verify() {
     pk.verify(b"message", &signature).unwrap();
 }
 
+#[test]
+fn secret_key_public_key() {
+    let sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    assert_eq!(sk.public_key(), Ed25519PublicKey::from(&sk));
+}
+
+#[test]
+fn implicit_account_id() {
+    let pk = Ed25519SecretKey::try_from_bytes(&random_bits())
+        .unwrap()
+        .public_key();
+    let account_id = pk.implicit_account_id();
+
+    assert_eq!(account_id.len(), 64);
+    assert!(account_id
+        .chars()
+        .all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch)));
+    assert!(account_id.is_implicit());
+
+    let decoded = (0..64)
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&account_id[idx..idx + 2], 16).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(decoded, pk.as_bytes());
+}
+
+#[test]
+fn keypair_generate() {
+    let keypair = Keypair::generate();
+    assert_eq!(
+        keypair.public_key(),
+        &Ed25519PublicKey::from(keypair.secret_key())
+    );
+
+    let signature = keypair.sign(b"message");
+    keypair.verify(b"message", &signature).unwrap();
+
+    let first = Keypair::generate_from_rng(&mut ChaChaRng::seed_from_u64(42));
+    let second = Keypair::generate_from_rng(&mut ChaChaRng::seed_from_u64(42));
+    assert_eq!(first.public_key(), second.public_key());
+    assert_ne!(first.public_key(), keypair.public_key());
+}
+
+#[test]
+fn public_key_verify_strict() {
+    let sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = Ed25519PublicKey::from(&sk);
+
+    let signature = sk.sign(b"message");
+    pk.verify_strict(b"message", &signature).unwrap();
+
+    assert!(matches!(
+        pk.verify_strict(b"another message", &signature),
+        Err(Error::Verification(..))
+    ));
+}
+
 #[test]
 fn keypair_verify() {
     let keypair = Keypair::new(Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap());
@@ -268,6 +325,75 @@ fn borsh_ed25519() {
     .unwrap();
 }
 
+#[test]
+fn borsh_secp256k1() {
+    let sk = Secp256k1SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let sk_bytes = borsh::to_vec(&sk).unwrap();
+    let pk = sk.public_key();
+    let pk_bytes = borsh::to_vec(&pk).unwrap();
+
+    assert_eq!(pk_bytes.len(), SECP256K1_PUBLIC_KEY_LENGTH + 1);
+    assert_eq!(pk_bytes[0], 1);
+    assert_eq!(sk_bytes.len(), SECP256K1_SECRET_KEY_LENGTH);
+
+    let sk = Secp256k1SecretKey::try_from_slice(&sk_bytes).unwrap();
+    let pk = Secp256k1PublicKey::try_from_slice(&pk_bytes).unwrap();
+
+    let hash = random_bits();
+    let signature_bytes = borsh::to_vec(&sk.sign(&hash).unwrap()).unwrap();
+    assert_eq!(signature_bytes.len(), SECP256K1_SIGNATURE_LENGTH + 1);
+    assert_eq!(signature_bytes[0], 1);
+
+    pk.verify(
+        &hash,
+        &Secp256k1Signature::try_from_slice(&signature_bytes).unwrap(),
+    )
+    .unwrap();
+
+    assert!(Ed25519PublicKey::try_from_slice(&pk_bytes).is_err());
+
+    // the keys are followed by another field, so they have to be read exactly
+    let bytes = borsh::to_vec(&(pk, 1_u64)).unwrap();
+    assert_eq!(
+        <(Secp256k1PublicKey, u64)>::try_from_slice(&bytes).unwrap(),
+        (pk, 1)
+    );
+
+    let bytes = borsh::to_vec(&(
+        Secp256k1SecretKey::try_from_slice(&sk_bytes).unwrap(),
+        2_u64,
+    ))
+    .unwrap();
+    let (decoded_sk, value) = <(Secp256k1SecretKey, u64)>::try_from_slice(&bytes).unwrap();
+    assert_eq!(decoded_sk.public_key(), pk);
+    assert_eq!(value, 2);
+
+    let signature = sk.sign(&hash).unwrap();
+    let bytes = borsh::to_vec(&(signature, 3_u64)).unwrap();
+    assert_eq!(
+        <(Secp256k1Signature, u64)>::try_from_slice(&bytes).unwrap(),
+        (signature, 3)
+    );
+}
+
+#[test]
+fn secp256k1_string() {
+    let sk = Secp256k1SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = sk.public_key();
+
+    let pk_str = pk.string();
+    assert!(pk_str.starts_with("secp256k1:"));
+    assert_eq!(Secp256k1PublicKey::from_string(&pk_str).unwrap(), pk);
+
+    let sk_restored = Secp256k1SecretKey::from_string(&sk.string()).unwrap();
+    assert_eq!(sk_restored.public_key(), pk);
+
+    assert!(matches!(
+        Secp256k1PublicKey::from_string(&sk.public_key().string().replace("secp256k1", "ed25519")),
+        Err(Error::WrongKeyType { .. })
+    ));
+}
+
 #[test]
 fn borsh_x25519() {
     let sk = SecretKey::try_from_bytes(&random_bits()).unwrap();
@@ -347,6 +473,118 @@ fn convert_from_edwards_to_montgomery_partially() {
     );
 }
 
+#[test]
+fn secret_key_from_mnemonic() {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let sk = Ed25519SecretKey::from_mnemonic(phrase, None, 0).unwrap();
+    let same_sk = Ed25519SecretKey::from_mnemonic(phrase, Some(""), 0).unwrap();
+    assert_eq!(sk.as_bytes(), same_sk.as_bytes());
+
+    let other_index = Ed25519SecretKey::from_mnemonic(phrase, None, 1).unwrap();
+    assert_ne!(sk.as_bytes(), other_index.as_bytes());
+
+    let with_password = Ed25519SecretKey::from_mnemonic(phrase, Some("password"), 0).unwrap();
+    assert_ne!(sk.as_bytes(), with_password.as_bytes());
+
+    assert!(matches!(
+        Ed25519SecretKey::from_mnemonic("abandon abandon abandon", None, 0),
+        Err(Error::InvalidMnemonic(..))
+    ));
+    assert!(matches!(
+        Ed25519SecretKey::from_mnemonic(&phrase.replace("about", "abandon"), None, 0),
+        Err(Error::InvalidMnemonic(..))
+    ));
+}
+
+#[test]
+fn secret_keys_zeroize_on_drop() {
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+
+    assert_zeroize_on_drop::<Ed25519SecretKey>();
+    assert_zeroize_on_drop::<SecretKey>();
+    assert_zeroize_on_drop::<Keypair>();
+}
+
+#[test]
+fn near_signature() {
+    let hash = random_bits();
+    let ed25519_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let secp256k1_sk = Secp256k1SecretKey::try_from_bytes(&random_bits()).unwrap();
+
+    for (signature, pk, key_type) in [
+        (
+            NearSignature::from(ed25519_sk.sign(&hash)),
+            NearPublicKey::from(ed25519_sk.public_key()),
+            "ed25519",
+        ),
+        (
+            NearSignature::from(secp256k1_sk.sign(&hash).unwrap()),
+            NearPublicKey::from(secp256k1_sk.public_key()),
+            "secp256k1",
+        ),
+    ] {
+        assert_eq!(signature.key_type(), key_type);
+        assert_eq!(
+            NearSignature::from_str(&signature.to_string()).unwrap(),
+            signature
+        );
+        signature.verify(&hash, &pk).unwrap();
+
+        // the signature is followed by another field, so it has to be read exactly
+        let bytes = borsh::to_vec(&(signature, 1_u64)).unwrap();
+        assert_eq!(
+            <(NearSignature, u64)>::try_from_slice(&bytes).unwrap(),
+            (signature, 1)
+        );
+    }
+
+    let ed25519_signature = ed25519_sk.sign(&hash);
+    assert_eq!(
+        borsh::to_vec(&NearSignature::from(ed25519_signature)).unwrap(),
+        borsh::to_vec(&ed25519_signature).unwrap()
+    );
+    assert!(NearSignature::from(ed25519_signature)
+        .verify(&hash, &secp256k1_sk.public_key().into())
+        .is_err());
+}
+
+#[test]
+fn near_public_key() {
+    let ed25519_pk = Ed25519SecretKey::try_from_bytes(&random_bits())
+        .unwrap()
+        .public_key();
+    let secp256k1_pk = Secp256k1SecretKey::try_from_bytes(&random_bits())
+        .unwrap()
+        .public_key();
+
+    for (pk, key_type) in [
+        (NearPublicKey::from(ed25519_pk), "ed25519"),
+        (NearPublicKey::from(secp256k1_pk), "secp256k1"),
+    ] {
+        assert_eq!(pk.key_type(), key_type);
+        assert_eq!(NearPublicKey::from_str(&pk.to_string()).unwrap(), pk);
+
+        // the key is followed by another field, so it has to be read exactly
+        let bytes = borsh::to_vec(&(pk, 1_u64)).unwrap();
+        assert_eq!(
+            <(NearPublicKey, u64)>::try_from_slice(&bytes).unwrap(),
+            (pk, 1)
+        );
+    }
+
+    assert_eq!(NearPublicKey::from(ed25519_pk), ed25519_pk);
+    assert_eq!(
+        borsh::to_vec(&NearPublicKey::from(ed25519_pk)).unwrap(),
+        borsh::to_vec(&ed25519_pk).unwrap()
+    );
+    assert!(matches!(
+        NearPublicKey::from_str(&ed25519_pk.string().replace("ed25519", "x25519")),
+        Err(Error::UnknownKeyType(..))
+    ));
+    assert!(NearPublicKey::try_from_slice(&[2; 33]).is_err());
+}
+
 fn random_bits() -> [u8; ED25519_SECRET_KEY_LENGTH] {
     let mut chacha = ChaChaRng::from_entropy();
     let mut secret_bytes = [0_u8; ED25519_SECRET_KEY_LENGTH];
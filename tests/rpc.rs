@@ -1,11 +1,20 @@
+use borsh::BorshDeserialize;
 use itertools::Itertools;
-use near_client::{prelude::*, Error, ViewAccessKeyCall};
+use near_client::{
+    core::hash::CryptoHash,
+    near_primitives_light::{
+        merkle::{compute_root_from_path, verify_hash},
+        transaction::{Action, SignedTransaction, TransferAction},
+    },
+    prelude::*,
+    Error, ViewAccessKeyCall,
+};
 use near_workspaces::{network::Sandbox, types::SecretKey, Worker};
 use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaChaRng;
 use reqwest::Url;
 use serde_json::json;
-use std::{fs::write, str::FromStr};
+use std::{fs::write, str::FromStr, time::Duration};
 
 // auxiliary structs and methods
 fn near_client(worker: &Worker<Sandbox>) -> NearClient {
@@ -39,7 +48,15 @@ async fn create_signer(
 }
 
 async fn download_contract() -> Vec<u8> {
-    let target = "https://github.com/near-examples/FT/raw/master/res/fungible_token.wasm";
+    download_wasm("https://github.com/near-examples/FT/raw/master/res/fungible_token.wasm").await
+}
+
+async fn download_nft_contract() -> Vec<u8> {
+    download_wasm("https://github.com/near-examples/NFT/raw/master/res/non_fungible_token.wasm")
+        .await
+}
+
+async fn download_wasm(target: &str) -> Vec<u8> {
     let target_path = temp_dir().into_path();
     let fname = "contract.wasm";
     let full_dest = format!("{}/{}", target_path.to_string_lossy(), fname);
@@ -83,6 +100,28 @@ async fn contract_creation() {
         .unwrap();
 }
 
+#[tokio::test]
+async fn contract_deploy_and_verify() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    let expected_hash = near_client::utils::code_hash(&wasm);
+
+    client
+        .deploy_and_verify(&signer, &signer_account_id, wasm)
+        .await
+        .unwrap();
+
+    let code = client
+        .view_contract_code(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+    assert_eq!(code.hash, expected_hash);
+}
+
 #[tokio::test]
 async fn contract_function_call() {
     let worker = near_workspaces::sandbox().await.unwrap();
@@ -110,39 +149,36 @@ async fn contract_function_call() {
 }
 
 #[tokio::test]
-async fn contract_function_call_with_wrong_nonce() {
+async fn contract_function_call_gas_profile() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
     let wasm = download_contract().await;
 
-    signer.update_nonce(0);
-
     client
         .deploy_contract(&signer, &signer_account_id, wasm)
-        .retry(Retry::ONCE)
         .commit(Finality::None)
         .await
         .unwrap();
 
-    signer.update_nonce(0);
-
-    client
+    let output = client
         .function_call(&signer, &signer_account_id, "new_default_meta")
         .args(json!({
             "owner_id": &signer_account_id,
             "total_supply": "100",
         }))
         .gas(gas("300 T"))
-        .retry(Retry::TWICE)
         .commit(Finality::None)
         .await
         .unwrap();
+
+    let gas_profile = output.gas_profile().unwrap();
+    assert!(gas_profile.iter().any(|cost| cost.gas_used > 0));
 }
 
 #[tokio::test]
-async fn contract_function_call_failed() {
+async fn contract_function_call_total_burnt() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
@@ -155,31 +191,54 @@ async fn contract_function_call_failed() {
         .await
         .unwrap();
 
-    assert!(client
+    let output = client
         .function_call(&signer, &signer_account_id, "new_default_meta")
         .args(json!({
             "owner_id": &signer_account_id,
-            "total_suppl": "100",
+            "total_supply": "100",
         }))
         .gas(gas("300 T"))
         .commit(Finality::None)
         .await
-        .is_err());
+        .unwrap();
 
-    client
+    // the receipt with the function call burns the gas as well
+    assert!(output.total_gas_burnt() > output.gas_burnt());
+    assert!(output.total_tokens_burnt() > 0);
+}
+
+#[tokio::test]
+async fn estimate_gas() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    let output = client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+    assert_eq!(output.metadata().version, 1);
+
+    let gas_burnt = client
         .function_call(&signer, &signer_account_id, "new_default_meta")
         .args(json!({
             "owner_id": &signer_account_id,
             "total_supply": "100",
         }))
         .gas(gas("300 T"))
-        .commit(Finality::None)
+        .build()
+        .unwrap()
+        .estimate_gas(Finality::None)
         .await
         .unwrap();
+    assert!(gas_burnt > 0 && gas_burnt < gas("300 T"));
 }
 
 #[tokio::test]
-async fn errors() {
+async fn contract_deploy_and_init() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
@@ -187,267 +246,323 @@ async fn errors() {
     let wasm = download_contract().await;
 
     client
-        .deploy_contract(&signer, &signer_account_id, wasm)
-        .commit(Finality::None)
-        .await
-        .unwrap();
-
-    signer.update_nonce(0);
-
-    // wrong nonce
-    assert!(matches!(
-        client
-            .function_call(&signer, &signer_account_id, "new_default_meta")
-            .args(json!({
+        .deploy_and_init(
+            &signer,
+            &signer_account_id,
+            wasm,
+            "new_default_meta",
+            json!({
                 "owner_id": &signer_account_id,
                 "total_supply": "100",
-            }))
-            .gas(gas("300 T"))
-            .retry(Retry::NONE)
-            .commit(Finality::None)
-            .await,
-        Err(Error::TxExecution(
-            TxExecutionError::InvalidTxError(InvalidTxError::InvalidNonce { .. }),
-            ..
-        ))
-    ));
-
-    // wrong arguments
-    assert!(matches!(
-        client
-            .function_call(&signer, &signer_account_id, "new_default_meta")
-            .args(json!({
-                "owner_id": &signer_account_id,
-                "total_suppl": "100",
-            }))
-            .gas(gas("300 T"))
-            .retry(Retry::ONCE)
-            .commit(Finality::None)
-            .await,
-        Err(Error::TxExecution(
-            TxExecutionError::ActionError(ActionError {
-                kind: ActionErrorKind::FunctionCallError(
-                    transaction_errors::FunctionCallError::ExecutionError(..)
-                ),
-                ..
             }),
-            ..
-        ))
-    ));
+            gas("300 T"),
+            0,
+        )
+        .unwrap()
+        .commit(Finality::None)
+        .await
+        .unwrap();
 
-    // wrong method
-    assert!(matches!(
-        client
-            .function_call(&signer, &signer_account_id, "new_default_met")
-            .args(json!({
-                "owner_id": &signer_account_id,
-                "total_supply": "100",
-            }))
-            .gas(gas("300 T"))
-            .commit(Finality::None)
-            .await,
-        Err(Error::TxExecution(
-            TxExecutionError::ActionError(ActionError {
-                kind: ActionErrorKind::FunctionCallError(
-                    transaction_errors::FunctionCallError::MethodResolveError(..)
-                ),
-                ..
-            }),
-            ..
-        ))
-    ));
+    let balance = client
+        .view::<String>(
+            &signer_account_id,
+            Finality::None,
+            "ft_balance_of",
+            Some(json!({ "account_id": &signer_account_id })),
+        )
+        .await
+        .unwrap()
+        .data();
+    assert_eq!(balance, "100");
 }
 
 #[tokio::test]
-async fn multiple_tests() {
+async fn fungible_token() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+    let wasm = download_contract().await;
 
-    let wasm = clone_and_compile_wasm().await;
+    client
+        .deploy_and_init(
+            &signer,
+            &signer_account_id,
+            wasm,
+            "new_default_meta",
+            json!({
+                "owner_id": &signer_account_id,
+                "total_supply": "100",
+            }),
+            gas("300 T"),
+            0,
+        )
+        .unwrap()
+        .commit(Finality::None)
+        .await
+        .unwrap();
 
-    init_contract(&client, &signer_account_id, &signer, wasm).await;
-    fc_no_params(&client, &signer_account_id, &signer).await;
-    fc_with_one_param_and_result(&client, &signer_account_id, &signer).await;
-    fc_with_param_and_result(&client, &signer_account_id, &signer).await;
-    view_no_params(&client, &signer_account_id).await;
-    view_with_params(&client, &signer_account_id).await;
-}
+    let metadata = client
+        .ft_metadata(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+    assert_eq!(metadata.spec, "ft-1.0.0");
+    assert_eq!(metadata.decimals, 24);
+    assert_eq!(
+        client
+            .ft_total_supply(&signer_account_id, Finality::None)
+            .await
+            .unwrap(),
+        100
+    );
 
-async fn init_contract(
-    client: &NearClient,
-    contract_id: &AccountId,
-    signer: &Signer,
-    wasm: Vec<u8>,
-) {
     client
-        .deploy_contract(signer, contract_id, wasm)
+        .storage_deposit(&signer, &signer_account_id, Some(&bob), near("0.00125"))
         .commit(Finality::None)
         .await
         .unwrap();
-}
 
-async fn view_no_params(client: &NearClient, contract_id: &AccountId) {
-    client
-        .view::<u64>(contract_id, Finality::None, "show_id", None)
+    let output = client
+        .ft_transfer(
+            &signer,
+            &signer_account_id,
+            &bob,
+            40,
+            Some("invoice #1".to_owned()),
+        )
+        .commit(Finality::None)
         .await
         .unwrap();
+    assert!(output.logs().iter().any(|log| log.contains("invoice #1")));
+
+    assert_eq!(
+        client
+            .ft_balance_of(&signer_account_id, &bob, Finality::None)
+            .await
+            .unwrap(),
+        40
+    );
+    assert_eq!(
+        client
+            .ft_balance_of(&signer_account_id, &signer_account_id, Finality::None)
+            .await
+            .unwrap(),
+        60
+    );
 }
 
-async fn view_with_params(client: &NearClient, contract_id: &AccountId) {
+#[tokio::test]
+async fn non_fungible_token() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+    let wasm = download_nft_contract().await;
+
     client
-        .view::<String>(
-            contract_id,
-            Finality::None,
-            "show_type",
-            Some(json!({"is_message": true})),
+        .deploy_and_init(
+            &signer,
+            &signer_account_id,
+            wasm,
+            "new_default_meta",
+            json!({ "owner_id": &signer_account_id }),
+            gas("300 T"),
+            0,
         )
+        .unwrap()
+        .commit(Finality::None)
         .await
         .unwrap();
-}
 
-// fc = function call
-async fn fc_no_params(client: &NearClient, contract_id: &AccountId, signer: &Signer) {
+    let metadata = client
+        .nft_metadata(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+    assert_eq!(metadata.spec, "nft-1.0.0");
+
     client
-        .function_call(signer, contract_id, "increment")
-        .gas(gas("300 T"))
+        .function_call(&signer, &signer_account_id, "nft_mint")
+        .args(json!({
+            "token_id": "1",
+            "receiver_id": &signer_account_id,
+            "token_metadata": { "title": "First" },
+        }))
+        .deposit(near("0.1"))
+        .gas(gas("30 T"))
         .commit(Finality::None)
         .await
         .unwrap();
-}
 
-async fn fc_with_one_param_and_result(
-    client: &NearClient,
-    contract_id: &AccountId,
-    signer: &Signer,
-) {
-    let expected_result = "change message";
-    let message = client
-        .function_call(signer, contract_id, "change_message")
-        .args(json!({ "message": expected_result }))
-        .gas(gas("300 T"))
-        .commit(Finality::Final)
+    let token = client
+        .nft_token(&signer_account_id, "1", Finality::None)
         .await
         .unwrap()
-        .output::<String>()
         .unwrap();
+    assert_eq!(token.owner_id, signer_account_id);
+    assert_eq!(token.metadata.unwrap().title.as_deref(), Some("First"));
+    assert!(client
+        .nft_token(&signer_account_id, "2", Finality::None)
+        .await
+        .unwrap()
+        .is_none());
 
-    assert_eq!(message, expected_result);
-}
+    client
+        .nft_transfer(&signer, &signer_account_id, &bob, "1", None, None)
+        .commit(Finality::None)
+        .await
+        .unwrap();
 
-async fn fc_with_param_and_result(client: &NearClient, contract_id: &AccountId, signer: &Signer) {
-    let expected_id = 666u64;
-    let id = client
-        .function_call(signer, contract_id, "change_id")
-        .args(json!({ "id": expected_id }))
-        .gas(gas("300 T"))
-        .commit(Finality::Final)
+    let tokens = client
+        .nft_tokens_for_owner(&signer_account_id, &bob, None, Some(10), Finality::None)
         .await
-        .unwrap()
-        .output::<u64>()
         .unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token_id, "1");
+    assert!(client
+        .nft_tokens_for_owner(
+            &signer_account_id,
+            &signer_account_id,
+            None,
+            None,
+            Finality::None
+        )
+        .await
+        .unwrap()
+        .is_empty());
+}
 
-    assert_eq!(id, expected_id);
+#[tokio::test]
+async fn client_timeout() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let rpc_url = Url::parse(worker.rpc_addr().as_str()).unwrap();
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+
+    let client = NearClient::new_with_timeout(rpc_url.clone(), Duration::from_nanos(1)).unwrap();
+    let err = client.view_account(&signer_account_id).await.unwrap_err();
+    assert!(err.to_string().contains("timed out"));
+
+    let client = NearClient::new_with_timeout(rpc_url, Duration::from_secs(60)).unwrap();
+    create_signer(&worker, &client, &signer_account_id).await;
+    client.view_account(&signer_account_id).await.unwrap();
 }
 
 #[tokio::test]
-async fn async_transaction() {
+async fn request_timeout() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
 
-    let wasm = clone_and_compile_wasm().await;
+    assert!(client
+        .with_timeout(Duration::from_nanos(1))
+        .view_account(&signer_account_id)
+        .await
+        .is_err());
 
+    // the timeout of a single view call
+    assert!(matches!(
+        client
+            .view_account(&signer_account_id)
+            .timeout(Duration::from_nanos(1))
+            .await,
+        Err(Error::RequestTimeout(..))
+    ));
     client
-        .deploy_contract(&signer, &signer_account_id, wasm)
-        .commit(Finality::None)
+        .view_account(&signer_account_id)
+        .timeout(Duration::from_secs(10))
         .await
         .unwrap();
 
-    let expected_result = "change message";
-    let transaction_id = client
-        .function_call(&signer, &signer_account_id, "change_message")
-        .args(json!({ "message": expected_result }))
-        .gas(gas("300 T"))
-        .commit_async(Finality::Final)
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .timeout(Duration::from_secs(60))
+        .commit(Finality::None)
         .await
         .unwrap();
+}
 
-    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
-
-    tokio::spawn(async move {
-        tokio::time::timeout(std::time::Duration::from_secs(3), rx)
-            .await
-            .expect("Wait async transaction timeout")
-    });
-
-    loop {
-        let res = client.view_transaction(&transaction_id, &signer).await;
-
-        if let Err(near_client::Error::ViewTransaction(_)) = &res {
-            // try one more time
-            continue;
-        }
+#[tokio::test]
+async fn retry_on_timeout() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
 
-        // cancel timeout
-        tx.send(()).unwrap();
-        let msg = res.unwrap().output::<String>().unwrap();
+    let err = client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .timeout(Duration::from_nanos(1))
+        .retry(Retry::TWICE)
+        .retry_backoff(Duration::from_millis(10))
+        .commit(Finality::None)
+        .await
+        .unwrap_err();
 
-        assert_eq!(msg, expected_result);
-        break;
-    }
+    assert!(matches!(err, Error::RpcError(..)));
 }
 
 #[tokio::test]
-async fn view_access_key_success() {
+async fn contract_function_call_with_wrong_nonce() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
 
-    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
-    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let pk = Ed25519PublicKey::from(&secret_key);
+    signer.update_nonce(0);
 
-    let _ = client
-        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .retry(Retry::ONCE)
         .commit(Finality::None)
         .await
-        .unwrap()
-        .output::<serde_json::Value>();
+        .unwrap();
 
-    let _ = client
-        .view_access_key(&new_acc, &pk, Finality::None)
+    signer.update_nonce(0);
+
+    client
+        .function_call(&signer, &signer_account_id, "new_default_meta")
+        .args(json!({
+            "owner_id": &signer_account_id,
+            "total_supply": "100",
+        }))
+        .gas(gas("300 T"))
+        .retry(Retry::TWICE)
+        .commit(Finality::None)
         .await
         .unwrap();
 }
 
 #[tokio::test]
-async fn view_access_key_failure() {
+async fn contract_function_call_with_backoff() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
 
-    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
-    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let pk = Ed25519PublicKey::from(&secret_key);
+    signer.update_nonce(0);
 
-    let access_key_err = client
-        .view_access_key(&new_acc, &pk, Finality::None)
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .retry(Retry::Backoff {
+            max_attempts: 3,
+            base: Duration::from_millis(100),
+            factor: 2.0,
+            jitter: true,
+        })
+        .commit(Finality::None)
         .await
-        .unwrap_err();
-
-    assert!(matches!(
-        access_key_err,
-        Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError { .. })
-    ));
+        .unwrap();
 }
 
 #[tokio::test]
-async fn view_contract_state() {
-    use base64::{engine::general_purpose, Engine as _};
-
+async fn function_call_with_block_hash() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
@@ -456,10 +571,11 @@ async fn view_contract_state() {
 
     client
         .deploy_contract(&signer, &signer_account_id, wasm)
-        .commit(Finality::Final)
+        .commit(Finality::None)
         .await
         .unwrap();
 
+    let block_hash = client.block(Finality::Final).await.unwrap();
     client
         .function_call(&signer, &signer_account_id, "new_default_meta")
         .args(json!({
@@ -467,158 +583,1154 @@ async fn view_contract_state() {
             "total_supply": "100",
         }))
         .gas(gas("300 T"))
-        .commit(Finality::Final)
+        .block_hash(block_hash)
+        .commit(Finality::None)
         .await
         .unwrap();
+}
 
-    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-
-    let state = client
-        .view_contract_state(&signer_account_id)
-        .await
-        .map(|state| {
-            state
-                .values
-                .into_iter()
-                .map(|state_item| {
-                    (
-                        general_purpose::STANDARD_NO_PAD.encode(state_item.key),
-                        general_purpose::STANDARD_NO_PAD.encode(state_item.value),
-                    )
-                })
-                .collect_vec()
-        })
-        .unwrap();
+#[tokio::test]
+async fn offline_signed_transaction() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
 
-    assert_eq!(
-        state[0],
-        (
-            "U1RBVEU".to_owned(),
-            "AQAAAGFkAAAAAAAAAAAAAAAAAAAAfQAAAAAAAAABAAAAbQ".to_owned()
-        )
+    let block_hash = client.block(Finality::Final).await.unwrap();
+    let transaction = NearClient::sign_transaction_offline(
+        &signer,
+        bob.clone(),
+        vec![TransferAction { deposit: near("1") }.into()],
+        signer.nonce() + 1,
+        block_hash,
     );
 
-    assert_eq!(
-        state[1],
-        (
-            "YQ8AAABhbGljZS50ZXN0Lm5lYXI".to_owned(),
-            "ZAAAAAAAAAAAAAAAAAAAAA".to_owned()
-        )
-    );
+    // the transaction is transferred to the connected machine as bytes
+    let bytes = borsh::to_vec(&transaction).unwrap();
+    let transaction = SignedTransaction::try_from_slice(&bytes).unwrap();
 
-    assert_eq!(
-        state[2],
-        (
-            "bQ".to_owned(),
-            "CAAAAGZ0LTEuMC4wGwAAAEV4YW1wbGUgTkVBUiBmdW5naWJsZSB0b2tlbgcA\
-            AABFWEFNUExFAX0CAABkYXRhOmltYWdlL3N2Zyt4bWwsJTNDc3ZnIHhtbG5zPSd\
-            odHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Zycgdmlld0JveD0nMCAwIDI4OCAyODgnJ\
-            TNFJTNDZyBpZD0nbCcgZGF0YS1uYW1lPSdsJyUzRSUzQ3BhdGggZD0nTTE4Ny41OCw3OS\
-            44MWwtMzAuMSw0NC42OWEzLjIsMy4yLDAsMCwwLDQuNzUsNC4yTDE5MS44NiwxMDNhMS4yL\
-            DEuMiwwLDAsMSwyLC45MXY4MC40NmExLjIsMS4yLDAsMCwxLTIuMTIuNzdMMTAyLjE4LDc3L\
-            jkzQTE1LjM1LDE1LjM1LDAsMCwwLDkwLjQ3LDcyLjVIODcuMzRBMTUuMzQsMTUuMzQsMCwwL\
-            DAsNzIsODcuODRWMjAxLjE2QTE1LjM0LDE1LjM0LDAsMCwwLDg3LjM0LDIxNi41aDBhMTUuM\
-            zUsMTUuMzUsMCwwLDAsMTMuMDgtNy4zMWwzMC4xLTQ0LjY5YTMuMiwzLjIsMCwwLDAtNC43N\
-            S00LjJMOTYuMTQsMTg2YTEuMiwxLjIsMCwwLDEtMi0uOTFWMTA0LjYxYTEuMiwxLjIsMCwwL\
-            DEsMi4xMi0uNzdsODkuNTUsMTA3LjIzYTE1LjM1LDE1LjM1LDAsMCwwLDExLjcxLDUuNDNoMy\
-            4xM0ExNS4zNCwxNS4zNCwwLDAsMCwyMTYsMjAxLjE2Vjg3Ljg0QTE1LjM0LDE1LjM0LDAsMCww\
-            LDIwMC42Niw3Mi41aDBBMTUuMzUsMTUuMzUsMCwwLDAsMTg3LjU4LDc5LjgxWicvJTNFJTNDL2c\
-            lM0UlM0Mvc3ZnJTNFAAAY"
-                .to_owned()
-        )
+    let output = client.broadcast_signed(&transaction).await.unwrap();
+    assert_eq!(output.id(), transaction.get_hash());
+
+    let transaction = NearClient::sign_transaction_offline(
+        &signer,
+        bob,
+        vec![TransferAction { deposit: near("1") }.into()],
+        signer.nonce() + 2,
+        block_hash,
     );
+    let id = client.broadcast_signed_async(&transaction).await.unwrap();
+    assert_eq!(id, transaction.get_hash());
 }
 
 #[tokio::test]
-async fn create_account() {
+async fn secp256k1_signer() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
 
-    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
-    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let pk = Ed25519PublicKey::from(&secret_key);
-
-    let _ = client
-        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
+    let secret_key = Secp256k1SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let public_key = secret_key.public_key();
+    client
+        .add_access_key(
+            &signer,
+            &signer_account_id,
+            public_key,
+            AccessKeyPermission::FullAccess,
+        )
         .commit(Finality::Final)
         .await
+        .unwrap();
+
+    let nonce = client
+        .view_access_key(&signer_account_id, public_key, Finality::Final)
+        .await
         .unwrap()
-        .output::<serde_json::Value>();
+        .nonce;
+    let secp256k1_signer =
+        Signer::from_secp256k1_secret(secret_key, signer_account_id.clone(), nonce);
+    assert_eq!(secp256k1_signer.public_key(), &public_key);
+
+    let transaction = client
+        .send(&secp256k1_signer, &bob, near("1"))
+        .build_signed_transaction(Finality::Final)
+        .await
+        .unwrap();
+    assert!(transaction.signature.as_secp256k1().is_some());
+    transaction
+        .signature
+        .verify(
+            transaction.get_hash().as_ref(),
+            secp256k1_signer.public_key(),
+        )
+        .unwrap();
 
-    let _ = client
-        .view_access_key(&new_acc, &pk, Finality::None)
+    let output = client.broadcast_signed(&transaction).await.unwrap();
+    assert_eq!(output.id(), transaction.get_hash());
+
+    // the nonce is tracked the same way as for the ed25519 keys
+    client
+        .send(&secp256k1_signer, &bob, near("1"))
+        .commit(Finality::Final)
         .await
         .unwrap();
 }
 
 #[tokio::test]
-async fn delete_account() {
+async fn build_signed_transaction() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
 
-    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
-    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let pk = Ed25519PublicKey::from(&secret_key);
-
-    client
-        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
-        .commit(Finality::Final)
+    let transaction = client
+        .send(&signer, &bob, near("1"))
+        .build_signed_transaction(Finality::None)
         .await
         .unwrap();
+    // the hash is known before the transaction is sent
+    let id = transaction.get_hash();
 
-    let access_key = client
-        .view_access_key(&new_acc, &pk, Finality::None)
-        .await
-        .unwrap();
+    let output = client.broadcast_signed(&transaction).await.unwrap();
+    assert_eq!(output.id(), id);
+    assert_eq!(
+        client.view_transaction(&id, &signer).await.unwrap().id(),
+        id
+    );
+}
 
-    let acc_signer = Signer::from_secret(secret_key, new_acc.clone(), access_key.nonce);
+#[tokio::test]
+async fn relay_delegate_action() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let relayer = AccountId::from_str("relayer.test.near").unwrap();
+    let relayer_signer = create_signer(&worker, &client, &relayer).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let bob_balance = client.view_account(&bob).await.unwrap().amount();
+    let max_block_height = worker.view_block().await.unwrap().height() + 100;
+
+    let signed_delegate = NearClient::build_delegate_action(
+        &alice_signer,
+        bob.clone(),
+        vec![TransferAction { deposit: near("1") }.into()],
+        max_block_height,
+    )
+    .unwrap();
 
     client
-        .delete_account(&acc_signer, &new_acc, &signer_account_id)
+        .relay_delegate(&relayer_signer, &signed_delegate)
         .commit(Finality::Final)
         .await
         .unwrap();
 
-    let access_key_err = client
-        .view_access_key(&new_acc, &pk, Finality::None)
-        .await
-        .unwrap_err();
+    let account = client.view_account(&bob).await.unwrap();
+    assert_eq!(account.amount(), bob_balance + near("1"));
 
     assert!(matches!(
-        access_key_err,
-        Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError { .. })
+        NearClient::build_delegate_action(
+            &alice_signer,
+            bob,
+            vec![Action::Delegate(signed_delegate)],
+            max_block_height,
+        ),
+        Err(Error::NestedDelegateAction)
     ));
 }
 
 #[tokio::test]
-async fn add_access_key_success() {
+async fn transaction_with_expired_block_hash() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
     let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
     let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let receiver_id = AccountId::from_str("bob.test.near").unwrap();
+    create_signer(&worker, &client, &receiver_id).await;
 
-    // add full access key permission
-    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
-    let permission = AccessKeyPermission::FullAccess;
+    let stale_block_hash = client.block(Finality::Final).await.unwrap();
+    // Move further than the transaction validity period
+    worker.fast_forward(1000).await.unwrap();
+
+    assert!(matches!(
+        client
+            .send(&signer, &receiver_id, near("1"))
+            .block_hash(stale_block_hash)
+            .commit(Finality::None)
+            .await,
+        Err(Error::TxExecution(
+            TxExecutionError::InvalidTxError(InvalidTxError::Expired),
+            ..
+        ))
+    ));
 
     client
-        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
+        .send(&signer, &receiver_id, near("1"))
+        .block_hash(stale_block_hash)
+        .retry(Retry::ONCE)
         .commit(Finality::None)
         .await
         .unwrap();
+}
 
-    let view_access_key = client
-        .view_access_key(&signer_account_id, &new_acc_pk, Finality::None)
-        .await
-        .unwrap();
+#[tokio::test]
+async fn batch_transaction() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
 
-    let viewed_permission: AccessKeyPermission = view_access_key.permission.into();
-    assert_eq!(permission, viewed_permission);
+    let contract_id = AccountId::from_str("ft.alice.test.near").unwrap();
+    let contract_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+
+    client
+        .batch(&signer, &contract_id)
+        .create_account()
+        .transfer(near("10"))
+        .add_key(contract_sk.public_key(), AccessKeyPermission::FullAccess)
+        .deploy(wasm)
+        .function_call(
+            "new_default_meta",
+            Some(json!({
+                "owner_id": &contract_id,
+                "total_supply": "100",
+            })),
+            gas("200 T"),
+            0,
+        )
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let balance = client
+        .view::<String>(
+            &contract_id,
+            Finality::None,
+            "ft_balance_of",
+            Some(json!({ "account_id": &contract_id })),
+        )
+        .await
+        .unwrap()
+        .data();
+
+    assert_eq!(balance, "100");
+
+    let raw_balance = client
+        .view_raw(
+            &contract_id,
+            Finality::None,
+            "ft_balance_of",
+            Some(json!({ "account_id": &contract_id })),
+        )
+        .await
+        .unwrap()
+        .into_bytes();
+
+    assert_eq!(raw_balance, br#""100""#);
+
+    // borsh encoded fixed size array is the same as raw bytes
+    let borsh_balance = client
+        .view_borsh::<[u8; 5]>(
+            &contract_id,
+            Finality::None,
+            "ft_balance_of",
+            Some(json!({ "account_id": &contract_id })),
+        )
+        .await
+        .unwrap()
+        .data();
+    assert_eq!(&borsh_balance, br#""100""#);
+
+    assert!(matches!(
+        client
+            .view_borsh::<u64>(
+                &contract_id,
+                Finality::None,
+                "ft_balance_of",
+                Some(json!({ "account_id": &contract_id })),
+            )
+            .await,
+        Err(Error::DeserializeViewCallBorsh(..))
+    ));
+}
+
+#[tokio::test]
+async fn contract_function_call_failed() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    assert!(client
+        .function_call(&signer, &signer_account_id, "new_default_meta")
+        .args(json!({
+            "owner_id": &signer_account_id,
+            "total_suppl": "100",
+        }))
+        .gas(gas("300 T"))
+        .commit(Finality::None)
+        .await
+        .is_err());
+
+    client
+        .function_call(&signer, &signer_account_id, "new_default_meta")
+        .args(json!({
+            "owner_id": &signer_account_id,
+            "total_supply": "100",
+        }))
+        .gas(gas("300 T"))
+        .commit(Finality::None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn errors() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    signer.update_nonce(0);
+
+    // wrong nonce
+    assert!(matches!(
+        client
+            .function_call(&signer, &signer_account_id, "new_default_meta")
+            .args(json!({
+                "owner_id": &signer_account_id,
+                "total_supply": "100",
+            }))
+            .gas(gas("300 T"))
+            .retry(Retry::NONE)
+            .commit(Finality::None)
+            .await,
+        Err(Error::TxExecution(
+            TxExecutionError::InvalidTxError(InvalidTxError::InvalidNonce { .. }),
+            ..
+        ))
+    ));
+
+    // wrong arguments
+    assert!(matches!(
+        client
+            .function_call(&signer, &signer_account_id, "new_default_meta")
+            .args(json!({
+                "owner_id": &signer_account_id,
+                "total_suppl": "100",
+            }))
+            .gas(gas("300 T"))
+            .retry(Retry::ONCE)
+            .commit(Finality::None)
+            .await,
+        Err(Error::TxExecution(
+            TxExecutionError::ActionError(ActionError {
+                kind: ActionErrorKind::FunctionCallError(
+                    transaction_errors::FunctionCallError::ExecutionError(..)
+                ),
+                ..
+            }),
+            ..
+        ))
+    ));
+
+    // wrong method
+    assert!(matches!(
+        client
+            .function_call(&signer, &signer_account_id, "new_default_met")
+            .args(json!({
+                "owner_id": &signer_account_id,
+                "total_supply": "100",
+            }))
+            .gas(gas("300 T"))
+            .commit(Finality::None)
+            .await,
+        Err(Error::TxExecution(
+            TxExecutionError::ActionError(ActionError {
+                kind: ActionErrorKind::FunctionCallError(
+                    transaction_errors::FunctionCallError::MethodResolveError(..)
+                ),
+                ..
+            }),
+            ..
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn view_errors() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    // wrong view method
+    let err = client
+        .view::<String>(&signer_account_id, Finality::None, "ft_metadat", None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, Error::ViewFunctionCall { error, .. } if error.contains("MethodNotFound"))
+    );
+    assert!(err.logs().is_some());
+}
+
+#[tokio::test]
+async fn multiple_tests() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let wasm = clone_and_compile_wasm().await;
+
+    init_contract(&client, &signer_account_id, &signer, wasm).await;
+    fc_no_params(&client, &signer_account_id, &signer).await;
+    fc_with_one_param_and_result(&client, &signer_account_id, &signer).await;
+    fc_with_param_and_result(&client, &signer_account_id, &signer).await;
+    view_no_params(&client, &signer_account_id).await;
+    view_with_params(&client, &signer_account_id).await;
+}
+
+async fn init_contract(
+    client: &NearClient,
+    contract_id: &AccountId,
+    signer: &Signer,
+    wasm: Vec<u8>,
+) {
+    client
+        .deploy_contract(signer, contract_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+}
+
+async fn view_no_params(client: &NearClient, contract_id: &AccountId) {
+    client
+        .view::<u64>(contract_id, Finality::None, "show_id", None)
+        .await
+        .unwrap();
+}
+
+async fn view_with_params(client: &NearClient, contract_id: &AccountId) {
+    client
+        .view::<String>(
+            contract_id,
+            Finality::None,
+            "show_type",
+            Some(json!({"is_message": true})),
+        )
+        .await
+        .unwrap();
+}
+
+// fc = function call
+async fn fc_no_params(client: &NearClient, contract_id: &AccountId, signer: &Signer) {
+    client
+        .function_call(signer, contract_id, "increment")
+        .gas(gas("300 T"))
+        .commit(Finality::None)
+        .await
+        .unwrap();
+}
+
+async fn fc_with_one_param_and_result(
+    client: &NearClient,
+    contract_id: &AccountId,
+    signer: &Signer,
+) {
+    let expected_result = "change message";
+    let message = client
+        .function_call(signer, contract_id, "change_message")
+        .args(json!({ "message": expected_result }))
+        .gas(gas("300 T"))
+        .commit(Finality::Final)
+        .await
+        .unwrap()
+        .output::<String>()
+        .unwrap();
+
+    assert_eq!(message, expected_result);
+}
+
+async fn fc_with_param_and_result(client: &NearClient, contract_id: &AccountId, signer: &Signer) {
+    let expected_id = 666u64;
+    let id = client
+        .function_call(signer, contract_id, "change_id")
+        .args(json!({ "id": expected_id }))
+        .gas(gas("300 T"))
+        .commit(Finality::Final)
+        .await
+        .unwrap()
+        .output::<u64>()
+        .unwrap();
+
+    assert_eq!(id, expected_id);
+}
+
+#[tokio::test]
+async fn async_transaction() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let wasm = clone_and_compile_wasm().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let expected_result = "change message";
+    let transaction_id = client
+        .function_call(&signer, &signer_account_id, "change_message")
+        .args(json!({ "message": expected_result }))
+        .gas(gas("300 T"))
+        .commit_async(Finality::Final)
+        .await
+        .unwrap();
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+    tokio::spawn(async move {
+        tokio::time::timeout(std::time::Duration::from_secs(3), rx)
+            .await
+            .expect("Wait async transaction timeout")
+    });
+
+    loop {
+        let res = client.view_transaction(&transaction_id, &signer).await;
+
+        if let Err(near_client::Error::ViewTransaction(_)) = &res {
+            // try one more time
+            continue;
+        }
+
+        // cancel timeout
+        tx.send(()).unwrap();
+        let msg = res.unwrap().output::<String>().unwrap();
+
+        assert_eq!(msg, expected_result);
+        break;
+    }
+}
+
+#[tokio::test]
+async fn tx_status_with_receipts() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let id = client
+        .send(&signer, &bob, near("1"))
+        .commit(Finality::Final)
+        .await
+        .unwrap()
+        .id();
+
+    let outcome = client.tx_status_with_receipts(&id, &signer).await.unwrap();
+    assert_eq!(outcome.final_outcome.transaction.hash, id);
+    assert!(outcome
+        .receipts
+        .iter()
+        .any(|receipt| receipt.receiver_id == bob && receipt.predecessor_id == signer_account_id));
+}
+
+#[tokio::test]
+async fn await_transaction() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let wasm = clone_and_compile_wasm().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let expected_result = "change message";
+    let transaction_id = client
+        .function_call(&signer, &signer_account_id, "change_message")
+        .args(json!({ "message": expected_result }))
+        .gas(gas("300 T"))
+        .commit_async(Finality::Final)
+        .await
+        .unwrap();
+
+    let msg = client
+        .await_transaction(
+            &transaction_id,
+            &signer,
+            Duration::from_millis(100),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap()
+        .output::<String>()
+        .unwrap();
+    assert_eq!(msg, expected_result);
+
+    let unknown_transaction = CryptoHash::default();
+    assert!(matches!(
+        client
+            .await_transaction(
+                &unknown_transaction,
+                &signer,
+                Duration::from_millis(100),
+                Duration::from_millis(500),
+            )
+            .await,
+        Err(Error::AwaitTransactionTimeout(..))
+    ));
+}
+
+#[tokio::test]
+async fn view_access_key_success() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = Ed25519PublicKey::from(&secret_key);
+
+    let _ = client
+        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
+        .commit(Finality::None)
+        .await
+        .unwrap()
+        .output::<serde_json::Value>();
+
+    let _ = client
+        .view_access_key(&new_acc, &pk, Finality::None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn view_access_key_failure() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = Ed25519PublicKey::from(&secret_key);
+
+    let access_key_err = client
+        .view_access_key(&new_acc, &pk, Finality::None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        access_key_err,
+        Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError { .. })
+    ));
+}
+
+#[tokio::test]
+async fn verify_signer() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    assert!(client.verify_signer(&signer, Finality::None).await.unwrap());
+
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let impostor = Signer::from_secret(secret_key, signer_account_id, 0);
+
+    assert!(!client
+        .verify_signer(&impostor, Finality::None)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn current_epoch_id() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let epoch_id = client.current_epoch_id(Finality::Final).await.unwrap();
+    let block = worker.view_block().await.unwrap();
+
+    assert_eq!(epoch_id.to_string(), block.epoch_id().to_string());
+}
+
+#[tokio::test]
+async fn validate_actions_limits() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let limits = client.actions_limits(Finality::None).await.unwrap();
+
+    client
+        .function_call(&signer, &signer_account_id, "ft_transfer")
+        .gas(limits.max_total_prepaid_gas)
+        .build()
+        .unwrap()
+        .validate(&limits)
+        .unwrap();
+
+    let err = client
+        .function_call(&signer, &signer_account_id, "ft_transfer")
+        .gas(limits.max_total_prepaid_gas + 1)
+        .build()
+        .unwrap()
+        .validate(&limits)
+        .err()
+        .unwrap();
+
+    assert!(matches!(
+        err,
+        Error::BatchLimitsExceeded(ActionsValidationError::TotalPrepaidGasExceeded { .. })
+    ));
+}
+
+#[tokio::test]
+async fn view_contract_state() {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    client
+        .function_call(&signer, &signer_account_id, "new_default_meta")
+        .args(json!({
+            "owner_id": &signer_account_id,
+            "total_supply": "100",
+        }))
+        .gas(gas("300 T"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    let state = client
+        .view_contract_state(&signer_account_id)
+        .await
+        .map(|state| {
+            state
+                .values
+                .into_iter()
+                .map(|state_item| {
+                    (
+                        general_purpose::STANDARD_NO_PAD.encode(state_item.key),
+                        general_purpose::STANDARD_NO_PAD.encode(state_item.value),
+                    )
+                })
+                .collect_vec()
+        })
+        .unwrap();
+
+    assert_eq!(
+        state[0],
+        (
+            "U1RBVEU".to_owned(),
+            "AQAAAGFkAAAAAAAAAAAAAAAAAAAAfQAAAAAAAAABAAAAbQ".to_owned()
+        )
+    );
+
+    assert_eq!(
+        state[1],
+        (
+            "YQ8AAABhbGljZS50ZXN0Lm5lYXI".to_owned(),
+            "ZAAAAAAAAAAAAAAAAAAAAA".to_owned()
+        )
+    );
+
+    assert_eq!(
+        state[2],
+        (
+            "bQ".to_owned(),
+            "CAAAAGZ0LTEuMC4wGwAAAEV4YW1wbGUgTkVBUiBmdW5naWJsZSB0b2tlbgcA\
+            AABFWEFNUExFAX0CAABkYXRhOmltYWdlL3N2Zyt4bWwsJTNDc3ZnIHhtbG5zPSd\
+            odHRwOi8vd3d3LnczLm9yZy8yMDAwL3N2Zycgdmlld0JveD0nMCAwIDI4OCAyODgnJ\
+            TNFJTNDZyBpZD0nbCcgZGF0YS1uYW1lPSdsJyUzRSUzQ3BhdGggZD0nTTE4Ny41OCw3OS\
+            44MWwtMzAuMSw0NC42OWEzLjIsMy4yLDAsMCwwLDQuNzUsNC4yTDE5MS44NiwxMDNhMS4yL\
+            DEuMiwwLDAsMSwyLC45MXY4MC40NmExLjIsMS4yLDAsMCwxLTIuMTIuNzdMMTAyLjE4LDc3L\
+            jkzQTE1LjM1LDE1LjM1LDAsMCwwLDkwLjQ3LDcyLjVIODcuMzRBMTUuMzQsMTUuMzQsMCwwL\
+            DAsNzIsODcuODRWMjAxLjE2QTE1LjM0LDE1LjM0LDAsMCwwLDg3LjM0LDIxNi41aDBhMTUuM\
+            zUsMTUuMzUsMCwwLDAsMTMuMDgtNy4zMWwzMC4xLTQ0LjY5YTMuMiwzLjIsMCwwLDAtNC43N\
+            S00LjJMOTYuMTQsMTg2YTEuMiwxLjIsMCwwLDEtMi0uOTFWMTA0LjYxYTEuMiwxLjIsMCwwL\
+            DEsMi4xMi0uNzdsODkuNTUsMTA3LjIzYTE1LjM1LDE1LjM1LDAsMCwwLDExLjcxLDUuNDNoMy\
+            4xM0ExNS4zNCwxNS4zNCwwLDAsMCwyMTYsMjAxLjE2Vjg3Ljg0QTE1LjM0LDE1LjM0LDAsMCww\
+            LDIwMC42Niw3Mi41aDBBMTUuMzUsMTUuMzUsMCwwLDAsMTg3LjU4LDc5LjgxWicvJTNFJTNDL2c\
+            lM0UlM0Mvc3ZnJTNFAAAY"
+                .to_owned()
+        )
+    );
+}
+
+#[tokio::test]
+async fn view_contract_state_with_prefix() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let wasm = download_contract().await;
+
+    client
+        .deploy_contract(&signer, &signer_account_id, wasm)
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    client
+        .function_call(&signer, &signer_account_id, "new_default_meta")
+        .args(json!({
+            "owner_id": &signer_account_id,
+            "total_supply": "100",
+        }))
+        .gas(gas("300 T"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let state = client
+        .view_contract_state_with_prefix(&signer_account_id, b"a", Finality::Final.into())
+        .await
+        .unwrap();
+
+    assert_eq!(state.values.len(), 1);
+    assert!(state.values[0].key.starts_with(b"a"));
+
+    // The balances are stored by a prefix and a borsh serialized account id
+    let balance_key = [b"a".as_slice(), &borsh::to_vec(&signer_account_id).unwrap()].concat();
+    assert_eq!(state.get_borsh::<u128>(&balance_key).unwrap(), Some(100));
+    assert_eq!(state.get_borsh::<u128>(b"unknown").unwrap(), None);
+    assert!(state.get_borsh::<String>(&balance_key).is_err());
+
+    let map = state.into_map();
+    assert_eq!(map.get(&balance_key), Some(&100u128.to_le_bytes().to_vec()));
+
+    let state = client
+        .view_contract_state_with_prefix(&signer_account_id, b"STATE", Finality::Final.into())
+        .await
+        .unwrap();
+
+    assert_eq!(state.values.len(), 1);
+    assert_eq!(state.values[0].key, b"STATE");
+}
+
+#[tokio::test]
+async fn create_account() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = Ed25519PublicKey::from(&secret_key);
+
+    let _ = client
+        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
+        .commit(Finality::Final)
+        .await
+        .unwrap()
+        .output::<serde_json::Value>();
+
+    let _ = client
+        .view_access_key(&new_acc, &pk, Finality::None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delete_account() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let new_acc = AccountId::from_str("one.alice.test.near").unwrap();
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let pk = Ed25519PublicKey::from(&secret_key);
+
+    client
+        .create_account(&signer, &new_acc, pk, near_units::parse_near!("3 N"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let access_key = client
+        .view_access_key(&new_acc, &pk, Finality::None)
+        .await
+        .unwrap();
+
+    let acc_signer = Signer::from_secret(secret_key, new_acc.clone(), access_key.nonce);
+
+    client
+        .delete_account(&acc_signer, &new_acc, &signer_account_id)
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let access_key_err = client
+        .view_access_key(&new_acc, &pk, Finality::None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        access_key_err,
+        Error::ViewAccessKeyCall(ViewAccessKeyCall::ParseError { .. })
+    ));
+}
+
+#[tokio::test]
+async fn add_access_key_success() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    // add full access key permission
+    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
+    let permission = AccessKeyPermission::FullAccess;
+
+    client
+        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let view_access_key = client
+        .view_access_key(&signer_account_id, &new_acc_pk, Finality::None)
+        .await
+        .unwrap();
+
+    let viewed_permission: AccessKeyPermission = view_access_key.permission.into();
+    assert_eq!(permission, viewed_permission);
+
+    // add permission for a single function execution only
+    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
+    let permission = AccessKeyPermission::FunctionCall(FunctionCallPermission {
+        allowance: None,
+        receiver_id: "some_contract".to_string(),
+        method_names: vec!["some_function".to_string()],
+    });
+
+    client
+        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let view_access_key = client
+        .view_access_key(&signer_account_id, &new_acc_pk, Finality::None)
+        .await
+        .unwrap();
+    let viewed_permission: AccessKeyPermission = view_access_key.permission.into();
+    assert_eq!(permission, viewed_permission);
+}
+
+#[tokio::test]
+async fn add_function_call_key() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let receiver_id = AccountId::from_str("some_contract.test.near").unwrap();
+
+    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
+
+    client
+        .add_function_call_key(
+            &signer,
+            &signer_account_id,
+            new_acc_pk,
+            receiver_id.clone(),
+            vec!["some_function".to_string()],
+            Some(near("1")),
+        )
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let view_access_key = client
+        .view_access_key(&signer_account_id, &new_acc_pk, Finality::None)
+        .await
+        .unwrap();
+    let viewed_permission: AccessKeyPermission = view_access_key.permission.into();
+    assert_eq!(
+        viewed_permission,
+        AccessKeyPermission::FunctionCall(FunctionCallPermission {
+            allowance: Some(near("1")),
+            receiver_id: receiver_id.to_string(),
+            method_names: vec!["some_function".to_string()],
+        })
+    );
+
+    assert!(view_access_key.permits(&receiver_id, "some_function"));
+    assert!(!view_access_key.permits(&receiver_id, "other_function"));
+    assert!(!view_access_key.permits(&signer_account_id, "some_function"));
+
+    let full_access_key = client
+        .view_access_key(&signer_account_id, signer.public_key(), Finality::None)
+        .await
+        .unwrap();
+    assert!(full_access_key.permits(&receiver_id, "any_function"));
+}
+
+#[tokio::test]
+async fn function_call_key_allowance() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let receiver_id = AccountId::from_str("some_contract.test.near").unwrap();
+
+    let fn_key_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let fn_key_pk = Ed25519PublicKey::from(&fn_key_sk);
+
+    client
+        .add_function_call_key(
+            &signer,
+            &signer_account_id,
+            fn_key_pk,
+            receiver_id,
+            vec![],
+            Some(near("0.01")),
+        )
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client
+            .access_key_allowance(&signer_account_id, &fn_key_pk, Finality::None)
+            .await
+            .unwrap(),
+        Some(near("0.01"))
+    );
+    assert_eq!(
+        client
+            .access_key_allowance(&signer_account_id, signer.public_key(), Finality::None)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let fn_key_signer = Signer::from_secret(fn_key_sk, signer_account_id.clone(), 0);
+    let gas_price = client.gas_price(None).await.unwrap();
+
+    client
+        .check_allowance(&fn_key_signer, gas("1 Tgas"), gas_price, Finality::None)
+        .await
+        .unwrap();
+
+    let err = client
+        .check_allowance(&fn_key_signer, gas("300 Tgas"), gas_price, Finality::None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::InvalidAccessKey(InvalidAccessKeyError::NotEnoughAllowance { allowance, .. })
+            if allowance == near("0.01")
+    ));
+
+    // full access key doesn't have an allowance
+    client
+        .check_allowance(&signer, gas("300 Tgas"), gas_price, Finality::None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn add_access_key_failed() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &signer_account_id).await;
+
+    let impostor_account_id = AccountId::from_str("impostor.test.near").unwrap();
+    let impostor_signer = create_signer(&worker, &client, &impostor_account_id).await;
+
+    // will fail due to the lack of permissions
+    assert!(client
+        .add_access_key(
+            &impostor_signer,
+            &signer_account_id,
+            *impostor_signer.public_key(),
+            AccessKeyPermission::FullAccess
+        )
+        .commit(Finality::None)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn view_access_key_list_success() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    // add full access key permission
+    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
+    let permission = AccessKeyPermission::FullAccess;
+
+    client
+        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
+        .commit(Finality::None)
+        .await
+        .unwrap();
 
     // add permission for a single function execution only
     let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
@@ -630,126 +1742,663 @@ async fn add_access_key_success() {
     });
 
     client
-        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
-        .commit(Finality::None)
+        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    // let's count all of the keys
+    let access_key_list = client
+        .view_access_key_list(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+
+    assert_eq!(access_key_list.keys.len(), 3);
+}
+
+#[tokio::test]
+async fn network_status() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    assert!(client.network_status().await.is_ok());
+}
+
+#[tokio::test]
+async fn network_status_cache() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let status = client.network_status().await.unwrap();
+
+    // chain id and protocol version are cached from the status call
+    assert_eq!(client.chain_id().await.unwrap(), status.chain_id);
+    assert_eq!(
+        client.protocol_version().await.unwrap(),
+        status.protocol_version
+    );
+}
+
+#[tokio::test]
+async fn latest_block_height() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let height = worker.view_block().await.unwrap().height();
+    assert!(client.latest_block_height(Finality::None).await.unwrap() >= height);
+}
+
+#[tokio::test]
+async fn network_status_info() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let status: StatusResponse = client.network_status().await.unwrap();
+
+    assert!(!status.version.version.is_empty());
+    assert!(!status.validators.is_empty());
+    assert!(!status.sync_info.syncing);
+    assert!(status.sync_info.latest_block_height > 0);
+}
+
+#[tokio::test]
+async fn block_view() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let latest = client.block_view(BlockReference::latest()).await.unwrap();
+    assert!(latest.header.height > 0);
+    assert!(!latest.chunks.is_empty());
+
+    let by_height = client
+        .block_view(BlockId::Height(latest.header.height).into())
+        .await
+        .unwrap();
+    assert_eq!(by_height.header.hash, latest.header.hash);
+
+    let by_hash = client
+        .block_view(BlockId::Hash(latest.header.hash).into())
+        .await
+        .unwrap();
+    assert_eq!(by_hash.header.height, latest.header.height);
+
+    assert_eq!(
+        client
+            .block(BlockId::Height(latest.header.height))
+            .await
+            .unwrap(),
+        latest.header.hash
+    );
+}
+
+#[tokio::test]
+async fn client_from_reqwest() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let rpc_url = Url::parse(worker.rpc_addr().as_str()).unwrap();
+    let client = NearClient::from_reqwest(reqwest::Client::new(), rpc_url);
+
+    assert!(client.block(Finality::None).await.is_ok());
+}
+
+#[tokio::test]
+async fn gas_price() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    assert!(client.gas_price(None).await.unwrap() > 0);
+
+    let block_height = worker.view_block().await.unwrap().height();
+    assert!(
+        client
+            .gas_price(Some(BlockId::Height(block_height)))
+            .await
+            .unwrap()
+            > 0
+    );
+}
+
+#[tokio::test]
+async fn chunk() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let block_view = worker.view_block().await.unwrap();
+    let chunk = client
+        .chunk(ChunkReference::BlockShardId {
+            block_id: BlockId::Height(block_view.height()),
+            shard_id: 0,
+        })
+        .await
+        .unwrap();
+
+    let by_hash = client
+        .chunk(ChunkReference::ChunkHash {
+            chunk_id: chunk.header.chunk_hash,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(chunk.header.chunk_hash, by_hash.header.chunk_hash);
+    assert_eq!(chunk.header.shard_id, 0);
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn mock_transport() {
+    use near_client::transport::MockTransport;
+
+    let client = NearClient::from_transport(
+        MockTransport::default().respond("gas_price", Ok(json!({ "gas_price": "100" }))),
+    );
+    assert_eq!(client.gas_price(None).await.unwrap(), 100);
+}
+
+#[tokio::test]
+async fn validators() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let validators = client.validators(None).await.unwrap();
+    let validator = validators.current_validators.first().unwrap();
+
+    assert!(validator.stake > 0);
+    assert!(validator.block_stats().produced <= validator.block_stats().expected);
+    // The sandbox validator is created with an ed25519 key
+    assert!(validator.public_key.as_ed25519().is_some());
+}
+
+#[tokio::test]
+async fn concurrent_transactions() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+    let nonce = alice_signer.nonce();
+
+    let transfers = (0..3).map(|_| {
+        client
+            .send(&alice_signer, &bob, near("1 Near"))
+            .commit(Finality::Final)
+    });
+    for output in futures::future::join_all(transfers).await {
+        output.unwrap();
+    }
+
+    assert_eq!(alice_signer.nonce(), nonce + 3);
+
+    let secret_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let signer = Signer::from_secret(secret_key, alice, 5);
+    assert_eq!(signer.reserve_nonce(), 6);
+    assert_eq!(signer.reserve_nonce(), 7);
+    assert_eq!(signer.nonce(), 7);
+}
+
+#[tokio::test]
+async fn rotate_full_access_key() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+    let new_keypair = Keypair::generate();
+
+    client
+        .rotate_full_access_key(
+            &signer,
+            &signer_account_id,
+            signer.public_key().to_owned(),
+            new_keypair.public_key().to_owned(),
+        )
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let access_key_list = client
+        .view_access_key_list(&signer_account_id, Finality::Final)
+        .await
+        .unwrap();
+
+    assert_eq!(access_key_list.keys.len(), 1);
+    assert_eq!(
+        &access_key_list.keys[0].public_key,
+        new_keypair.public_key()
+    );
+
+    let primary_access_key = client
+        .primary_access_key(&signer_account_id, Finality::Final)
+        .await
+        .unwrap();
+    assert_eq!(&primary_access_key.public_key, new_keypair.public_key());
+}
+
+#[tokio::test]
+async fn changes() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let output = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+    let outcome = client
+        .status_of(
+            TransactionOrReceiptId::Transaction {
+                transaction_hash: output.id(),
+                sender_id: alice.clone(),
+            },
+            Finality::Final,
+        )
+        .await
+        .unwrap();
+
+    let changes = client
+        .changes(
+            StateChangesRequest::AccountChanges {
+                account_ids: vec![alice.clone()],
+            },
+            BlockId::Hash(outcome.block_hash).into(),
+        )
+        .await
+        .unwrap();
+
+    assert!(changes.iter().any(|change| matches!(
+        (&change.cause, &change.value),
+        (
+            StateChangeCause::TransactionProcessing { tx_hash },
+            StateChangeValue::AccountUpdate { account_id, .. },
+        ) if *tx_hash == output.id() && *account_id == alice
+    )));
+
+    let changes = client
+        .changes(
+            StateChangesRequest::SingleAccessKeyChanges {
+                keys: vec![AccountWithPublicKey {
+                    account_id: alice.clone(),
+                    public_key: alice_signer.public_key().into(),
+                }],
+            },
+            BlockId::Hash(outcome.block_hash).into(),
+        )
+        .await
+        .unwrap();
+
+    assert!(changes.iter().any(|change| matches!(
+        &change.value,
+        StateChangeValue::AccessKeyUpdate { account_id, .. } if *account_id == alice
+    )));
+}
+
+#[tokio::test]
+async fn delete_access_key() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    client
+        .delete_access_key(&signer, &signer_account_id, signer.public_key().to_owned())
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let access_key_list = client
+        .view_access_key_list(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+
+    assert_eq!(access_key_list.keys.len(), 0);
+}
+
+#[tokio::test]
+async fn primary_access_key() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let access_key = client
+        .primary_access_key(&signer_account_id, Finality::None)
+        .await
+        .unwrap();
+
+    assert_eq!(&access_key.public_key, signer.public_key());
+
+    client
+        .delete_access_key(&signer, &signer_account_id, signer.public_key().to_owned())
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client
+            .primary_access_key(&signer_account_id, Finality::Final)
+            .await,
+        Err(Error::NoFullAccessKey(..))
+    ));
+}
+
+#[tokio::test]
+async fn view_account() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &alice).await;
+    let account = client.view_account(&alice).await.unwrap();
+
+    assert_eq!(near_to_human(account.amount()), "100 N");
+}
+
+#[tokio::test]
+async fn send() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let alice_account = client.view_account(&alice).await.unwrap();
+    let bob_account = client.view_account(&bob).await.unwrap();
+
+    assert!(bob_account.amount() > alice_account.amount());
+}
+
+#[tokio::test]
+async fn send_near_token() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let bob_balance = client.view_account(&bob).await.unwrap().amount();
+    let amount = NearToken::from_near_str("1 Near").unwrap();
+    client
+        .send(&alice_signer, &bob, amount)
+        .commit(Finality::Final)
         .await
         .unwrap();
 
-    let view_access_key = client
-        .view_access_key(&signer_account_id, &new_acc_pk, Finality::None)
+    let bob_account = client.view_account(&bob).await.unwrap();
+    assert_eq!(bob_account.amount(), bob_balance + Balance::from(amount));
+
+    client
+        .send_checked(&alice_signer, &bob, NearToken::from_yocto(1))
+        .unwrap()
+        .commit(Finality::Final)
         .await
         .unwrap();
-    let viewed_permission: AccessKeyPermission = view_access_key.permission.into();
-    assert_eq!(permission, viewed_permission);
 }
 
 #[tokio::test]
-async fn add_access_key_failed() {
+async fn output_display() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
-    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
-    let _ = create_signer(&worker, &client, &signer_account_id).await;
 
-    let impostor_account_id = AccountId::from_str("impostor.test.near").unwrap();
-    let impostor_signer = create_signer(&worker, &client, &impostor_account_id).await;
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
 
-    // will fail due to the lack of permissions
-    assert!(client
-        .add_access_key(
-            &impostor_signer,
-            &signer_account_id,
-            *impostor_signer.public_key(),
-            AccessKeyPermission::FullAccess
-        )
-        .commit(Finality::None)
+    let output = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
         .await
-        .is_err());
+        .unwrap();
+
+    assert_eq!(
+        output.to_string(),
+        format!(
+            "Transaction {}: succeeded, gas burnt: {}, logs: 0",
+            output.id(),
+            gas_to_human(output.total_gas_burnt())
+        )
+    );
 }
 
 #[tokio::test]
-async fn view_access_key_list_success() {
+async fn status_of_transaction() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
-    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
-    let signer = create_signer(&worker, &client, &signer_account_id).await;
 
-    // add full access key permission
-    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
-    let permission = AccessKeyPermission::FullAccess;
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
 
-    client
-        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
-        .commit(Finality::None)
+    let output = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
         .await
         .unwrap();
 
-    // add permission for a single function execution only
-    let new_acc_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
-    let new_acc_pk = Ed25519PublicKey::from(&new_acc_sk);
-    let permission = AccessKeyPermission::FunctionCall(FunctionCallPermission {
-        allowance: None,
-        receiver_id: "some_contract".to_string(),
-        method_names: vec!["some_function".to_string()],
-    });
+    let outcome = client
+        .status_of(
+            TransactionOrReceiptId::Transaction {
+                transaction_hash: output.id(),
+                sender_id: alice,
+            },
+            Finality::Final,
+        )
+        .await
+        .unwrap();
 
-    client
-        .add_access_key(&signer, &signer_account_id, new_acc_pk, permission.clone())
-        .commit(Finality::None)
+    assert_eq!(outcome.id, output.id());
+}
+
+#[tokio::test]
+async fn view_receipt() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let output = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+    let receipt = client
+        .tx_status_with_receipts(&output.id(), &alice_signer)
         .await
+        .unwrap()
+        .receipts
+        .into_iter()
+        .find(|receipt| receipt.receiver_id == bob)
         .unwrap();
 
-    // let's count all of the keys
-    let access_key_list = client
-        .view_access_key_list(&signer_account_id, Finality::None)
+    // The outcome is available, when the next block is final, so it's awaited
+    let outcome = client
+        .view_receipt(receipt.receipt_id, bob.clone())
         .await
         .unwrap();
+    assert_eq!(outcome.executor_id, receipt.receiver_id);
+
+    let outcome = client
+        .await_receipt(
+            receipt.receipt_id,
+            bob,
+            Duration::from_millis(100),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.executor_id, receipt.receiver_id);
 
-    assert_eq!(access_key_list.keys.len(), 3);
+    assert!(matches!(
+        client
+            .await_receipt(
+                CryptoHash::default(),
+                alice,
+                Duration::from_millis(100),
+                Duration::from_millis(500),
+            )
+            .await,
+        Err(Error::AwaitReceiptTimeout(..))
+    ));
 }
 
 #[tokio::test]
-async fn network_status() {
+async fn light_client_proof() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
-    assert!(client.network_status().await.is_ok());
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let output = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    // The light client head has to be after the block with the outcome
+    worker.fast_forward(3).await.unwrap();
+    let light_client_head = client.block(Finality::Final).await.unwrap();
+
+    let proof = client
+        .light_client_proof(
+            TransactionOrReceiptId::Transaction {
+                transaction_hash: output.id(),
+                sender_id: alice,
+            },
+            light_client_head,
+        )
+        .await
+        .unwrap();
+    assert_eq!(proof.outcome_proof.id, output.id());
+
+    let outcome_hash = CryptoHash::hash_borsh(proof.outcome_proof.to_hashes());
+    let shard_outcome_root = compute_root_from_path(&proof.outcome_proof.proof, outcome_hash);
+    assert!(near_client::light_client::verify_outcome_proof(
+        &proof.outcome_proof,
+        &proof.outcome_proof.proof,
+        shard_outcome_root
+    ));
+    assert!(verify_hash(
+        proof.block_header_lite.inner_lite.outcome_root,
+        &proof.outcome_root_proof,
+        CryptoHash::hash_borsh(shard_outcome_root)
+    ));
 }
 
 #[tokio::test]
-async fn delete_access_key() {
+async fn stake() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
-    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
-    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
 
     client
-        .delete_access_key(&signer, &signer_account_id, signer.public_key().to_owned())
-        .commit(Finality::None)
+        .stake(
+            &alice_signer,
+            &alice,
+            near("50"),
+            alice_signer.public_key().to_owned(),
+        )
+        .commit(Finality::Final)
         .await
         .unwrap();
 
-    let access_key_list = client
-        .view_access_key_list(&signer_account_id, Finality::None)
+    let account = client.view_account(&alice).await.unwrap();
+    assert_eq!(account.locked(), near("50"));
+}
+
+#[tokio::test]
+async fn view_account_at_block() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    let block_hash = client.block(Finality::Final).await.unwrap();
+    let block_height = worker.view_block().await.unwrap().height();
+    let account_before = client
+        .view_account_at(&bob, BlockId::Hash(block_hash).into())
         .await
         .unwrap();
 
-    assert_eq!(access_key_list.keys.len(), 0);
+    client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit(Finality::Final)
+        .await
+        .unwrap();
+
+    let account_at_hash = client
+        .view_account_at(&bob, BlockId::Hash(block_hash).into())
+        .await
+        .unwrap();
+    let account_at_height = client
+        .view_account_at(&bob, BlockId::Height(block_height).into())
+        .await
+        .unwrap();
+    let account_after = client.view_account(&bob).await.unwrap();
+
+    let detailed = client
+        .view_account_detailed(&bob, BlockId::Height(block_height).into())
+        .await
+        .unwrap();
+    assert_eq!(detailed.block_height, block_height);
+    assert_eq!(detailed.account.amount, account_at_height.amount());
+
+    assert_eq!(account_at_hash.amount(), account_before.amount());
+    assert!(account_at_height.amount() < account_after.amount());
 }
 
 #[tokio::test]
-async fn view_account() {
+async fn view_accounts() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
 
     let alice = AccountId::from_str("alice.test.near").unwrap();
     let _ = create_signer(&worker, &client, &alice).await;
-    let account = client.view_account(&alice).await.unwrap();
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+    let unknown = AccountId::from_str("unknown.test.near").unwrap();
 
-    assert_eq!(near_to_human(account.amount()), "100 N");
+    let accounts = client
+        .view_accounts(&[alice.clone(), unknown, bob.clone()])
+        .await
+        .unwrap();
+    assert_eq!(accounts.len(), 3);
+
+    let alice_account = client.view_account(&alice).await.unwrap();
+    assert_eq!(
+        accounts[0].as_ref().unwrap().amount(),
+        alice_account.amount()
+    );
+    assert!(matches!(accounts[1], Err(Error::ViewCall(..))));
+    assert!(accounts[2].is_ok());
+
+    assert!(client.view_accounts(&[]).await.unwrap().is_empty());
 }
 
 #[tokio::test]
-async fn send() {
+async fn commit_with_cancel() {
     let worker = near_workspaces::sandbox().await.unwrap();
     let client = near_client(&worker);
 
@@ -758,16 +2407,195 @@ async fn send() {
     let bob = AccountId::from_str("bob.test.near").unwrap();
     let _ = create_signer(&worker, &client, &bob).await;
 
+    let res = client
+        .send(&alice_signer, &bob, near("1 Near"))
+        .commit_with_cancel(Finality::Final, futures::future::ready(()))
+        .await;
+    assert!(matches!(res, Err(Error::Cancelled)));
+
     client
         .send(&alice_signer, &bob, near("1 Near"))
+        .commit_with_cancel(Finality::Final, futures::future::pending())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn view_access_keys() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &alice).await;
+
+    let second_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let second_pk = Ed25519PublicKey::from(&second_sk);
+    client
+        .add_access_key(&signer, &alice, second_pk, AccessKeyPermission::FullAccess)
+        .commit(Finality::None)
+        .await
+        .unwrap();
+
+    let unknown_sk = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let unknown_pk = Ed25519PublicKey::from(&unknown_sk);
+
+    let keys = client
+        .view_access_keys(
+            &alice,
+            [*signer.public_key(), unknown_pk.into(), second_pk.into()],
+            Finality::None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(keys.len(), 3);
+    assert_eq!(keys[0].as_ref().unwrap().nonce, signer.nonce());
+    assert!(keys[1].is_err());
+    assert!(keys[2].is_ok());
+
+    assert!(client
+        .view_access_keys(&alice, Vec::<NearPublicKey>::new(), Finality::None)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn send_checked() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+
+    let alice = AccountId::from_str("alice.test.near").unwrap();
+    let alice_signer = create_signer(&worker, &client, &alice).await;
+    let bob = AccountId::from_str("bob.test.near").unwrap();
+    let _ = create_signer(&worker, &client, &bob).await;
+
+    assert!(matches!(
+        client.send_checked(&alice_signer, &bob, NearToken::from_yocto(0)),
+        Err(Error::ZeroTransfer(..))
+    ));
+
+    client
+        .send_checked(&alice_signer, &bob, near("1 Near"))
+        .unwrap()
         .commit(Finality::Final)
         .await
         .unwrap();
+}
 
-    let alice_account = client.view_account(&alice).await.unwrap();
-    let bob_account = client.view_account(&bob).await.unwrap();
+#[test]
+fn error_logs() {
+    let logs = vec!["panicked at 'Not enough balance'".to_owned()];
+    let err = Error::TxExecution(
+        TxExecutionError::InvalidTxError(InvalidTxError::Expired),
+        Box::new(logs.clone()),
+    );
+    assert_eq!(err.logs(), Some(logs.as_slice()));
 
-    assert!(bob_account.amount() > alice_account.amount());
+    let err = Error::ZeroTransfer(AccountId::from_str("bob.test.near").unwrap());
+    assert_eq!(err.logs(), None);
+}
+
+#[tokio::test]
+async fn signer_fetch() {
+    let worker = near_workspaces::sandbox().await.unwrap();
+    let client = near_client(&worker);
+    let signer_account_id = AccountId::from_str("alice.test.near").unwrap();
+    let signer = create_signer(&worker, &client, &signer_account_id).await;
+
+    let secret_key =
+        Ed25519SecretKey::try_from_bytes(&signer.secret_key().unwrap().to_bytes()).unwrap();
+    let fetched = Signer::fetch(
+        &client,
+        secret_key,
+        signer_account_id.clone(),
+        Finality::None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(fetched.nonce(), signer.nonce());
+
+    let unknown_key = Ed25519SecretKey::try_from_bytes(&random_bits()).unwrap();
+    assert!(matches!(
+        Signer::fetch(&client, unknown_key, signer_account_id, Finality::None).await,
+        Err(Error::ViewAccessKeyCall(..))
+    ));
+}
+
+#[test]
+fn signer_credentials_round_trip() {
+    let keypair = Keypair::generate();
+    let credentials = format!(
+        r#"{{"account_id":"alice.test.near","public_key":"{}","private_key":"{}"}}"#,
+        keypair.public_key(),
+        keypair.to_string()
+    );
+
+    let dir = temp_dir();
+    let path = dir.path().join("alice.test.near.json");
+    write(&path, credentials.as_bytes()).unwrap();
+
+    let signer = Signer::from_credentials_file(&path, 0).unwrap();
+    assert_eq!(signer.account().as_str(), "alice.test.near");
+    assert_eq!(signer.public_key(), keypair.public_key());
+
+    let expected = serde_json::from_str::<serde_json::Value>(&credentials).unwrap();
+    let actual = serde_json::from_str::<serde_json::Value>(&signer.to_credentials_json()).unwrap();
+    assert_eq!(actual, expected);
+
+    write(&path, b"{}").unwrap();
+    assert!(matches!(
+        Signer::from_credentials_file(&path, 0),
+        Err(Error::CreateSigner(..))
+    ));
+
+    let malformed = format!(
+        r#"{{"account_id":"alice.test.near","public_key":"{}","private_key":"ed25519:malformed"}}"#,
+        keypair.public_key()
+    );
+    write(&path, malformed.as_bytes()).unwrap();
+    assert!(matches!(
+        Signer::from_credentials_file(&path, 0),
+        Err(Error::CreateSigner(..))
+    ));
+}
+
+#[test]
+fn signer_serde() {
+    let keypair = Keypair::generate();
+    let secret_key = Ed25519SecretKey::try_from_bytes(&keypair.secret_key().to_bytes()).unwrap();
+    let signer = Signer::from_secret(
+        secret_key,
+        AccountId::from_str("alice.test.near").unwrap(),
+        42,
+    );
+
+    let json = serde_json::to_value(&signer).unwrap();
+    assert_eq!(json["account_id"], "alice.test.near");
+    assert_eq!(json["secret_key"], keypair.secret_key().string());
+    assert_eq!(json["nonce"], 42);
+
+    let deserialized = serde_json::from_value::<Signer>(json).unwrap();
+    assert_eq!(deserialized.account(), signer.account());
+    assert_eq!(deserialized.public_key(), signer.public_key());
+    assert_eq!(deserialized.nonce(), signer.nonce());
+
+    let secret_key = Secp256k1SecretKey::try_from_bytes(&random_bits()).unwrap();
+    let public_key = secret_key.public_key();
+    let signer = Signer::from_secp256k1_secret(
+        secret_key,
+        AccountId::from_str("alice.test.near").unwrap(),
+        42,
+    );
+
+    let json = serde_json::to_value(&signer).unwrap();
+    assert!(json["secret_key"]
+        .as_str()
+        .unwrap()
+        .starts_with("secp256k1:"));
+    assert!(signer.secret_key().is_none());
+
+    let deserialized = serde_json::from_value::<Signer>(json).unwrap();
+    assert_eq!(deserialized.public_key(), &public_key);
 }
 
 fn temp_dir() -> tempfile::TempDir {